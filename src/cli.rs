@@ -1,7 +1,10 @@
 // cli command definitions using clap derive macros
 // keeps it simple and user-friendly
 
-use clap::{Parser, Subcommand};
+use crate::generator::plan::ExistingFiles;
+use crate::models::CmsType;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "portfolio-arcade")]
@@ -12,6 +15,10 @@ use clap::{Parser, Subcommand};
     long_about = "creates beautiful, interactive portfolio websites themed after classic gaming consoles like ps3, ps5, and nintendo wii. includes cms integration, responsive design, and modern web technologies."
 )]
 pub struct Cli {
+    /// print a machine-readable json result instead of colored output
+    #[arg(long, global = true)]
+    pub json: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -19,10 +26,306 @@ pub struct Cli {
 #[derive(Subcommand)]
 pub enum Commands {
     /// initialize a new portfolio project with interactive setup
-    Init {
-        /// name of the project (will prompt if not provided)
-        project_name: Option<String>,
-    },
+    Init(InitArgs),
     /// check for updates and show update instructions
     Update,
+    /// refresh github data (stars, forks, topics, descriptions), skills and posts in a generated portfolio
+    Sync {
+        /// path to the generated portfolio
+        #[arg(default_value = ".")]
+        path: PathBuf,
+    },
+    /// add a github repository or a hand-described project to a generated portfolio
+    AddProject(AddProjectArgs),
+    /// remove a project from a generated portfolio by id
+    RemoveProject {
+        /// id of the project to remove
+        id: String,
+
+        /// path to the generated portfolio
+        #[arg(long, default_value = ".")]
+        path: PathBuf,
+    },
+    /// generate one portfolio per row of a csv or json roster
+    Batch(BatchArgs),
+    /// pack a portfolio's config and local images into a shareable .arcade cartridge
+    Pack {
+        /// path to the generated portfolio
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// pack a portfolio config file instead, images are read from public/ next to it
+        #[arg(long, value_name = "FILE")]
+        config: Option<PathBuf>,
+
+        /// cartridge to write (defaults to <name>.arcade)
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// unpack a cartridge into portfolio.json and public/ for editing
+    Unpack {
+        /// the .arcade cartridge
+        cartridge: PathBuf,
+
+        /// directory to unpack into (defaults to the cartridge's name)
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// regenerate a portfolio with another console theme, keeping its content
+    SwitchTheme {
+        /// theme to switch to: ps3, ps5 or wii
+        theme: String,
+
+        /// path to the generated portfolio
+        #[arg(long, default_value = ".")]
+        path: PathBuf,
+
+        /// where to write the new portfolio (defaults to <path>-<theme>)
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// pick up changes from the bundled template, keeping your own edits
+    Upgrade {
+        /// path to the generated portfolio
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// report what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// record a missing base and manifest from the bundled template and the
+        /// portfolio's own config, without changing any of its files
+        #[arg(long, conflicts_with = "dry_run")]
+        record_base: bool,
+    },
+    /// install dependencies and build a static export of a generated portfolio into out/
+    Build {
+        /// path to the generated portfolio
+        #[arg(default_value = ".")]
+        path: PathBuf,
+    },
+    /// preview a built portfolio's out/ directory without node
+    Serve {
+        /// path to the generated portfolio
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// port to listen on (defaults to the portfolio's dev port)
+        #[arg(long)]
+        port: Option<u16>,
+    },
+    /// publish a built portfolio's out/ directory
+    ///
+    /// the branch is replaced by a single commit of the export. a branch whose tip wasn't
+    /// made by deploy is refused unless --force is passed, since its history would be lost
+    Deploy(DeployArgs),
+    /// check templates, node, package manager, port, github token and updates
+    Doctor {
+        /// project directory used to detect the package manager
+        #[arg(long, default_value = ".")]
+        path: PathBuf,
+
+        /// dev server port to check (defaults to the portfolio's dev port, or 3000)
+        #[arg(long)]
+        port: Option<u16>,
+    },
+    /// export portfolio data for other tools
+    Export {
+        /// output format
+        #[arg(long, value_enum, default_value_t = ExportFormat::JsonResume)]
+        format: ExportFormat,
+
+        /// path to the generated portfolio
+        #[arg(long, default_value = ".")]
+        path: PathBuf,
+
+        /// export from a portfolio config file instead of a generated portfolio
+        #[arg(long, value_name = "FILE")]
+        config: Option<PathBuf>,
+
+        /// file to write
+        #[arg(long, short, default_value = "resume.json")]
+        output: PathBuf,
+    },
+}
+
+impl Commands {
+    // whether the command may stop and wait for keyboard input
+    pub fn is_interactive(&self) -> bool {
+        match self {
+            Commands::Init(args) => !args.is_headless(),
+            Commands::Update => true,
+            Commands::Sync { .. } => false,
+            Commands::AddProject(_) => false,
+            Commands::RemoveProject { .. } => false,
+            Commands::Batch(_) => false,
+            Commands::Pack { .. } => false,
+            Commands::Unpack { .. } => false,
+            Commands::SwitchTheme { .. } => false,
+            Commands::Upgrade { .. } => false,
+            Commands::Build { .. } => false,
+            Commands::Serve { .. } => false,
+            Commands::Deploy(_) => false,
+            Commands::Doctor { .. } => false,
+            Commands::Export { .. } => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ExportFormat {
+    /// https://jsonresume.org document
+    JsonResume,
+}
+
+#[derive(Args)]
+pub struct InitArgs {
+    /// name of the project (will prompt if not provided)
+    pub project_name: Option<String>,
+
+    /// portfolio config file (json) to generate from without the interactive setup
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// json resume (jsonresume.org) to fill in your details and projects
+    #[arg(long, value_name = "FILE")]
+    pub from_json_resume: Option<PathBuf>,
+
+    /// .arcade cartridge to regenerate a portfolio from, assets included
+    #[arg(long, value_name = "CARTRIDGE", conflicts_with_all = ["config", "from_json_resume"])]
+    pub from: Option<PathBuf>,
+
+    /// console theme to use: ps3, ps5 or wii
+    #[arg(long)]
+    pub theme: Option<String>,
+
+    /// cms integration: decap, payload or none
+    #[arg(long)]
+    pub cms: Option<CmsType>,
+
+    /// port for the development server
+    #[arg(long)]
+    pub port: Option<u16>,
+
+    /// github repository to include as a project (can be repeated)
+    #[arg(long = "repo", value_name = "URL")]
+    pub repos: Vec<String>,
+
+    /// list the files that would be generated and the portfolio data, without writing anything
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// generate the portfolio in every theme side by side, with a landing page to pick one
+    #[arg(long, conflicts_with_all = ["theme", "dry_run"])]
+    pub all_themes: bool,
+
+    /// generate into the project directory even if it already exists
+    #[arg(long)]
+    pub force: bool,
+
+    /// what --force does with existing files: overwrite, skip-existing or merge (json files)
+    #[arg(
+        long,
+        value_name = "POLICY",
+        default_value = "overwrite",
+        requires = "force"
+    )]
+    pub existing: ExistingFiles,
+}
+
+impl InitArgs {
+    // any config flag skips the tui and generates straight away
+    pub fn is_headless(&self) -> bool {
+        self.config.is_some()
+            || self.from_json_resume.is_some()
+            || self.from.is_some()
+            || self.theme.is_some()
+            || self.cms.is_some()
+            || self.port.is_some()
+            || !self.repos.is_empty()
+            || self.dry_run
+            || self.all_themes
+            || self.force
+    }
+}
+
+#[derive(Args)]
+pub struct AddProjectArgs {
+    /// github repository url (leave out to describe the project with flags)
+    pub url: Option<String>,
+
+    /// path to the generated portfolio
+    #[arg(long, default_value = ".")]
+    pub path: PathBuf,
+
+    /// project title (required without a url)
+    #[arg(long)]
+    pub title: Option<String>,
+
+    /// one-line project description
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// technologies used, comma separated
+    #[arg(long, value_delimiter = ',')]
+    pub tech: Vec<String>,
+
+    /// url of the live site
+    #[arg(long)]
+    pub live: Option<String>,
+
+    /// highlight the project
+    #[arg(long)]
+    pub featured: bool,
+}
+
+#[derive(Args)]
+pub struct BatchArgs {
+    /// roster file: csv with a header row, or a json array
+    /// columns: name, author, title, theme, repos (github urls separated by spaces or semicolons)
+    pub roster: PathBuf,
+
+    /// directory to generate the portfolios in
+    #[arg(long, short, default_value = ".")]
+    pub output: PathBuf,
+
+    /// cms integration for every portfolio: decap, payload or none
+    #[arg(long)]
+    pub cms: Option<CmsType>,
+
+    /// regenerate portfolios whose directory already exists, overwriting generated files
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Args)]
+pub struct DeployArgs {
+    /// path to the generated portfolio
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// commit the export to a branch and push it (github pages)
+    #[arg(long)]
+    pub git: bool,
+
+    /// branch to publish to
+    #[arg(long, default_value = "gh-pages")]
+    pub branch: String,
+
+    /// remote name in the portfolio's repository, or a repository url
+    #[arg(long, default_value = "origin")]
+    pub remote: String,
+
+    /// custom domain to write into CNAME
+    #[arg(long, value_name = "DOMAIN")]
+    pub cname: Option<String>,
+
+    /// commit message for the deploy
+    #[arg(long, short)]
+    pub message: Option<String>,
+
+    /// replace the branch even if it holds commits that weren't made by deploy
+    #[arg(long)]
+    pub force: bool,
 }
@@ -0,0 +1,165 @@
+// batch command - one portfolio per roster row, for workshops and classes
+// a failing row is reported and skipped, the rest still get generated
+
+use crate::cli::BatchArgs;
+use crate::error::ArcadeError;
+use crate::generator::plan::ExistingFiles;
+use crate::generator::TemplateGenerator;
+use crate::models::{Roster, RosterEntry, UserDefaults};
+use crate::output::Output;
+use crate::utils::validation::{validate_config, validate_project_name};
+use anyhow::Result;
+use colored::*;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+#[derive(Debug, Serialize)]
+pub struct BatchResult {
+    // 1-based position in the roster
+    pub row: usize,
+    pub name: String,
+    pub ok: bool,
+    pub path: Option<PathBuf>,
+    pub theme: Option<String>,
+    pub project_count: usize,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+}
+
+pub async fn run(args: BatchArgs, out: &Output) -> Result<()> {
+    let entries = Roster::load(&args.roster)?;
+    out.line(
+        format!(
+            "generating {} portfolio(s) from {}",
+            entries.len(),
+            args.roster.display()
+        )
+        .cyan(),
+    );
+    out.line("");
+
+    let mut seen = HashSet::new();
+    let mut results = Vec::new();
+    for (index, entry) in entries.into_iter().enumerate() {
+        let row = index + 1;
+        let name = entry.name.clone();
+
+        let generated = if seen.insert(name.clone()) {
+            generate_row(&args, row, entry).await
+        } else {
+            Err(ArcadeError::InvalidConfig(format!("name: '{}' appears twice", name)).into())
+        };
+
+        let result = match generated {
+            Ok(result) => result,
+            Err(e) => BatchResult {
+                row,
+                name,
+                ok: false,
+                path: None,
+                theme: None,
+                project_count: 0,
+                warnings: Vec::new(),
+                error: Some(format!("{:#}", e)),
+            },
+        };
+        print_result(&result, out);
+        results.push(result);
+    }
+
+    let total = results.len();
+    let failed = results.iter().filter(|r| !r.ok).count();
+
+    out.line("");
+    let summary = format!("generated {} of {} portfolio(s)", total - failed, total);
+    if failed == 0 {
+        out.line(summary.green().bold());
+    } else {
+        out.line(format!("{}, {} failed", summary, failed).yellow().bold());
+    }
+
+    out.result(serde_json::json!({
+        "command": "batch",
+        "ok": failed == 0,
+        "roster": args.roster,
+        "output_dir": args.output,
+        "generated": total - failed,
+        "failed": failed,
+        "portfolios": results,
+    }));
+
+    if failed > 0 {
+        return Err(ArcadeError::BatchFailed { failed, total }.into());
+    }
+    Ok(())
+}
+
+// builds the row's config over the user defaults and generates it
+async fn generate_row(args: &BatchArgs, row: usize, entry: RosterEntry) -> Result<BatchResult> {
+    validate_project_name(&entry.name)
+        .map_err(|e| ArcadeError::InvalidConfig(format!("name: {}", e)))?;
+
+    let mut config = UserDefaults::load()?.config();
+    if let Some(author) = entry.author {
+        config.user.name = author;
+    }
+    if let Some(title) = entry.title {
+        config.user.title = title;
+    }
+    if let Some(theme) = entry.theme {
+        config.theme = theme.to_lowercase();
+    }
+    if let Some(cms) = &args.cms {
+        config.cms = cms.clone();
+    }
+    let mut warnings = Vec::new();
+    if !entry.repos.is_empty() {
+        let imported = crate::github::projects::urls_to_projects(&entry.repos).await;
+        config.add_skills(imported.skills());
+        config.projects = imported.projects;
+        warnings = imported.warnings;
+    }
+    validate_config(&config).map_err(ArcadeError::InvalidConfig)?;
+
+    let generator = TemplateGenerator::with_existing_policy(
+        entry.name.clone(),
+        config,
+        args.output.join(&entry.name),
+        args.force.then_some(ExistingFiles::Overwrite),
+    )?;
+    generator.generate().await?;
+
+    Ok(BatchResult {
+        row,
+        name: entry.name,
+        ok: true,
+        path: Some(generator.target_dir.clone()),
+        theme: Some(generator.theme.id.clone()),
+        project_count: generator.config.projects.len(),
+        warnings,
+        error: None,
+    })
+}
+
+fn print_result(result: &BatchResult, out: &Output) {
+    match &result.error {
+        None => out.line(format!(
+            "  {} {} ({}, {} project(s))",
+            "ok".green().bold(),
+            result.name,
+            result.theme.as_deref().unwrap_or_default(),
+            result.project_count
+        )),
+        Some(error) => out.line(format!(
+            "  {} row {} {}: {}",
+            "failed".red().bold(),
+            result.row,
+            result.name,
+            error
+        )),
+    }
+    for warning in &result.warnings {
+        out.warn(format!("{}: {}", result.name, warning));
+    }
+}
@@ -0,0 +1,97 @@
+// build command - turns a generated portfolio into a deployable static site
+// installs dependencies when needed and runs the next.js build in export mode
+
+use crate::generator::dependencies::{DependencyManager, STATIC_EXPORT_ENV};
+use crate::generator::GeneratedProject;
+use crate::output::Output;
+use crate::utils::files::{dir_size, format_size};
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::fs;
+use std::path::Path;
+
+// where next.js writes a static export
+pub const OUTPUT_DIR: &str = "out";
+
+pub async fn run(path: &Path, out: &Output) -> Result<()> {
+    let mut portfolio = GeneratedProject::open(path)?;
+    let mut warnings = Vec::new();
+
+    // the site should ship the posts in content/posts/, not the ones portfolio.json last saw
+    let config = portfolio.load_config()?;
+    let posts_updated = portfolio.posts_changed(&config);
+    if posts_updated {
+        portfolio.save_config(&config)?;
+        out.line(
+            format!(
+                "picked up {} post(s) from content/posts",
+                config.posts.len()
+            )
+            .cyan(),
+        );
+    }
+
+    // portfolios generated before static export support ignore the env var
+    if !supports_static_export(&portfolio.root) {
+        let warning = format!(
+            "next.config.mjs doesn't enable static export; add `output: 'export'` or set it from {}",
+            STATIC_EXPORT_ENV
+        );
+        out.warn(&warning);
+        warnings.push(warning);
+    }
+
+    DependencyManager::check_node_available().await?;
+    let manager = DependencyManager::new(portfolio.root.clone());
+
+    let installed = manager.needs_install();
+    if installed {
+        manager.install_dependencies(out).await?;
+    }
+
+    out.line("building static export...".cyan());
+    manager.build_static_export().await?;
+
+    let output_dir = portfolio.root.join(OUTPUT_DIR);
+    if !output_dir.join("index.html").exists() {
+        return Err(anyhow!(
+            "the build finished but {} has no index.html; check that next.config.mjs sets `output: 'export'`",
+            output_dir.display()
+        ));
+    }
+
+    let (bytes, files) = dir_size(&output_dir);
+    let output_dir = output_dir.canonicalize().unwrap_or(output_dir);
+
+    out.line("");
+    out.line("static site built!".green().bold());
+    out.line(format!("  {}: {}", "output".bold(), output_dir.display()));
+    out.line(format!(
+        "  {}: {} in {} files",
+        "size".bold(),
+        format_size(bytes),
+        files
+    ));
+
+    out.result(serde_json::json!({
+        "command": "build",
+        "path": portfolio.root,
+        "output_dir": output_dir,
+        "size_bytes": bytes,
+        "files": files,
+        "installed_dependencies": installed,
+        "posts_updated": posts_updated,
+        "warnings": warnings,
+    }));
+
+    Ok(())
+}
+
+// whether the project's next config turns on static export in some way
+fn supports_static_export(root: &Path) -> bool {
+    ["next.config.mjs", "next.config.js", "next.config.ts"]
+        .iter()
+        .map(|name| root.join(name))
+        .filter_map(|file| fs::read_to_string(file).ok())
+        .any(|content| content.contains(STATIC_EXPORT_ENV) || content.contains("'export'"))
+}
@@ -0,0 +1,128 @@
+// pack and unpack commands - .arcade cartridges for handing a portfolio to someone else
+// `init --from` regenerates the same site from a cartridge
+
+use crate::commands::init::load_config_file_as_written;
+use crate::generator::GeneratedProject;
+use crate::models::{Cartridge, PortfolioConfig, CARTRIDGE_EXTENSION};
+use crate::output::Output;
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::path::{Path, PathBuf};
+
+pub fn pack(
+    path: &Path,
+    config_file: Option<&Path>,
+    output: Option<PathBuf>,
+    out: &Output,
+) -> Result<()> {
+    // a config file's images sit in public/ next to it, like an unpacked cartridge
+    // the packer's own user defaults stay out of it, it's packed as written
+    let (config, public_dir, name): (PortfolioConfig, PathBuf, String) = match config_file {
+        Some(file) => {
+            let dir = file.parent().unwrap_or(Path::new("."));
+            let name = dir
+                .canonicalize()
+                .ok()
+                .and_then(|dir| dir.file_name().map(|n| n.to_string_lossy().to_string()))
+                .unwrap_or_else(|| "portfolio".to_string());
+            (load_config_file_as_written(file)?, dir.join("public"), name)
+        }
+        None => {
+            let portfolio = GeneratedProject::open(path)?;
+            (
+                portfolio.load_config()?,
+                portfolio.root.join("public"),
+                portfolio.name(),
+            )
+        }
+    };
+
+    let mut cartridge = Cartridge::new(config);
+    let missing = cartridge.collect_assets(&public_dir);
+    for asset in &missing {
+        out.warn(format!(
+            "{} not found in {}, the cartridge only references it",
+            asset,
+            public_dir.display()
+        ));
+    }
+
+    let output =
+        output.unwrap_or_else(|| PathBuf::from(format!("{}.{}", name, CARTRIDGE_EXTENSION)));
+    cartridge.write(&output)?;
+
+    out.line(format!("packed {}", output.display()).green().bold());
+    out.line(format!("  {}: {}", "theme".bold(), cartridge.theme));
+    out.line(format!(
+        "  {}: {}",
+        "projects".bold(),
+        cartridge.config.projects.len()
+    ));
+    out.line(format!("  {}: {}", "assets".bold(), cartridge.assets.len()));
+    out.line("");
+    out.line(format!(
+        "regenerate it with: portfolio-arcade init <name> --from {}",
+        output.display()
+    ));
+
+    let assets: Vec<&String> = cartridge.assets.keys().collect();
+    out.result(serde_json::json!({
+        "command": "pack",
+        "path": output,
+        "format_version": cartridge.format_version,
+        "theme": cartridge.theme,
+        "project_count": cartridge.config.projects.len(),
+        "assets": assets,
+        "missing_assets": missing,
+    }));
+
+    Ok(())
+}
+
+pub fn unpack(path: &Path, output: Option<PathBuf>, out: &Output) -> Result<()> {
+    let cartridge = Cartridge::read(path)?;
+
+    let dir = match output {
+        Some(dir) => dir,
+        None => PathBuf::from(
+            path.file_stem()
+                .ok_or_else(|| anyhow!("can't derive an output directory, pass --output"))?,
+        ),
+    };
+    let config_path = dir.join("portfolio.json");
+    if config_path.exists() {
+        return Err(anyhow!(
+            "{} already exists, pass another --output",
+            config_path.display()
+        ));
+    }
+    cartridge.unpack_to(&dir)?;
+
+    out.line(format!("unpacked into {}", dir.display()).green().bold());
+    out.line(format!(
+        "  {}: {} (packed by portfolio-arcade {})",
+        "theme".bold(),
+        cartridge.theme,
+        cartridge.tool_version
+    ));
+    out.line(format!("  {}: {}", "assets".bold(), cartridge.assets.len()));
+    out.line("");
+    out.line(format!(
+        "edit {} and repack with: portfolio-arcade pack --config {}",
+        config_path.display(),
+        config_path.display()
+    ));
+
+    let assets: Vec<&String> = cartridge.assets.keys().collect();
+    out.result(serde_json::json!({
+        "command": "unpack",
+        "path": dir,
+        "config": config_path,
+        "format_version": cartridge.format_version,
+        "tool_version": cartridge.tool_version,
+        "theme": cartridge.theme,
+        "assets": assets,
+    }));
+
+    Ok(())
+}
@@ -0,0 +1,232 @@
+// deploy command - publishes the static export to a git branch
+// builds a fresh orphan commit of out/ and force-pushes it, ready for github pages
+// a branch is only replaced when its tip is an earlier deploy, unless --force is passed
+
+use crate::cli::DeployArgs;
+use crate::commands::build::OUTPUT_DIR;
+use crate::generator::GeneratedProject;
+use crate::output::Output;
+use anyhow::{anyhow, Context, Result};
+use colored::*;
+use std::fs;
+use std::path::Path;
+use tokio::process::Command;
+
+// used when the machine has no git identity configured
+const FALLBACK_NAME: &str = "portfolio-arcade";
+const FALLBACK_EMAIL: &str = "portfolio-arcade@users.noreply.github.com";
+
+// marks deploy commits so the next deploy knows the branch is safe to replace
+const DEPLOY_TRAILER: &str = "Deployed-with: portfolio-arcade";
+
+pub async fn run(args: DeployArgs, out: &Output) -> Result<()> {
+    if !args.git {
+        return Err(anyhow!(
+            "choose where to deploy: `--git` publishes out/ to a branch"
+        ));
+    }
+
+    let portfolio = GeneratedProject::open(&args.path)?;
+    let export_dir = portfolio.root.join(OUTPUT_DIR);
+    if !export_dir.join("index.html").exists() {
+        return Err(anyhow!(
+            "{} has no index.html, run `portfolio-arcade build` first",
+            export_dir.display()
+        ));
+    }
+
+    git(&portfolio.root, &["--version"])
+        .await
+        .context("git is not installed or not on PATH")?;
+
+    let remote_url = resolve_remote(&portfolio.root, &args.remote).await?;
+
+    // the branch is built in a scratch repository so the user's checkout is never touched
+    let work = tempfile::tempdir()?;
+    let work_dir = work.path();
+
+    git(work_dir, &["init", "--quiet"]).await?;
+    git(
+        work_dir,
+        &[
+            "symbolic-ref",
+            "HEAD",
+            &format!("refs/heads/{}", args.branch),
+        ],
+    )
+    .await?;
+
+    let mut copy_options = fs_extra::dir::CopyOptions::new();
+    copy_options.content_only = true;
+    fs_extra::dir::copy(&export_dir, work_dir, &copy_options)
+        .with_context(|| format!("failed to copy {}", export_dir.display()))?;
+
+    // github pages would otherwise run jekyll and drop the _next directory
+    fs::write(work_dir.join(".nojekyll"), "")?;
+    if let Some(domain) = &args.cname {
+        fs::write(work_dir.join("CNAME"), format!("{}\n", domain))?;
+    }
+
+    git(work_dir, &["add", "--all"]).await?;
+
+    let message = format!(
+        "{}\n\n{}",
+        args.message
+            .clone()
+            .unwrap_or_else(|| format!("deploy {} with portfolio-arcade", portfolio.name())),
+        DEPLOY_TRAILER
+    );
+    let mut commit = vec![];
+    if git(work_dir, &["config", "user.email"]).await.is_err() {
+        commit.extend([
+            "-c".to_string(),
+            format!("user.name={}", FALLBACK_NAME),
+            "-c".to_string(),
+            format!("user.email={}", FALLBACK_EMAIL),
+        ]);
+    }
+    commit.extend(["commit", "--quiet", "-m", &message].map(String::from));
+    let commit: Vec<&str> = commit.iter().map(String::as_str).collect();
+    git(work_dir, &commit).await?;
+
+    let sha = git(work_dir, &["rev-parse", "HEAD"]).await?;
+
+    let branch_ref = format!("refs/heads/{}", args.branch);
+    let tip = remote_tip(work_dir, &remote_url, &branch_ref).await?;
+    if let Some(tip) = &tip {
+        if !args.force && !is_deploy_commit(work_dir, &remote_url, &branch_ref).await? {
+            return Err(anyhow!(
+                "{} on {} has commits that weren't made by deploy ({}), pass --force to replace its history",
+                args.branch,
+                args.remote,
+                &tip[..tip.len().min(7)]
+            ));
+        }
+    }
+
+    out.line(format!("pushing to {} ({})...", args.branch, args.remote).cyan());
+    // the lease fails the push if someone else updated the branch since it was checked
+    let lease = format!(
+        "--force-with-lease={}:{}",
+        branch_ref,
+        tip.as_deref().unwrap_or_default()
+    );
+    git(
+        work_dir,
+        &[
+            "push",
+            &lease,
+            "--quiet",
+            &remote_url,
+            &format!("HEAD:{}", branch_ref),
+        ],
+    )
+    .await
+    .context("failed to push the deploy branch")?;
+
+    out.line("");
+    out.line("portfolio deployed!".green().bold());
+    out.line(format!("  {}: {}", "branch".bold(), args.branch));
+    out.line(format!(
+        "  {}: {}",
+        "commit".bold(),
+        &sha[..sha.len().min(7)]
+    ));
+    if let Some(domain) = &args.cname {
+        out.line(format!("  {}: https://{}", "domain".bold(), domain));
+    }
+
+    out.result(serde_json::json!({
+        "command": "deploy",
+        "remote": args.remote,
+        "branch": args.branch,
+        "commit": sha,
+        "cname": args.cname,
+    }));
+
+    Ok(())
+}
+
+// a remote name of the portfolio's repository wins, then a url, then a path from the portfolio
+// local paths are made absolute so they survive pushing from the scratch repository
+async fn resolve_remote(root: &Path, remote: &str) -> Result<String> {
+    if let Ok(url) = git(root, &["remote", "get-url", remote]).await {
+        return Ok(local_path(root, &url).unwrap_or(url));
+    }
+    if is_url(remote) {
+        return Ok(remote.to_string());
+    }
+
+    local_path(root, remote).ok_or_else(|| {
+        anyhow!(
+            "'{}' is not a git remote of {} or a repository path; pass a remote url with --remote",
+            remote,
+            root.display()
+        )
+    })
+}
+
+// scheme urls and scp-style user@host:path
+fn is_url(remote: &str) -> bool {
+    remote.contains("://")
+        || remote
+            .split_once(':')
+            .is_some_and(|(host, _)| !host.is_empty() && !host.contains('/'))
+}
+
+fn local_path(root: &Path, remote: &str) -> Option<String> {
+    if is_url(remote) {
+        return None;
+    }
+    root.join(remote)
+        .canonicalize()
+        .ok()
+        .map(|path| path.to_string_lossy().to_string())
+}
+
+// the commit the branch points at on the remote, None when it doesn't exist yet
+async fn remote_tip(work_dir: &Path, remote_url: &str, branch_ref: &str) -> Result<Option<String>> {
+    let listed = git(work_dir, &["ls-remote", remote_url, branch_ref])
+        .await
+        .context("failed to read the deploy branch from the remote")?;
+    Ok(listed.split_whitespace().next().map(|sha| sha.to_string()))
+}
+
+// deploy commits carry the trailer; older ones are recognised as orphans with a .nojekyll
+async fn is_deploy_commit(work_dir: &Path, remote_url: &str, branch_ref: &str) -> Result<bool> {
+    git(
+        work_dir,
+        &["fetch", "--quiet", "--depth", "1", remote_url, branch_ref],
+    )
+    .await
+    .context("failed to fetch the deploy branch")?;
+
+    let message = git(work_dir, &["log", "-1", "--format=%B", "FETCH_HEAD"]).await?;
+    if message.lines().any(|line| line.trim() == DEPLOY_TRAILER) {
+        return Ok(true);
+    }
+    let parents = git(work_dir, &["log", "-1", "--format=%P", "FETCH_HEAD"]).await?;
+    Ok(parents.is_empty()
+        && git(work_dir, &["cat-file", "-e", "FETCH_HEAD:.nojekyll"])
+            .await
+            .is_ok())
+}
+
+// runs git in a directory and returns trimmed stdout
+async fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .await?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git {} failed: {}",
+            args.first().unwrap_or(&""),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
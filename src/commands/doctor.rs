@@ -0,0 +1,324 @@
+// doctor command - diagnoses everything generation and dev-serving depend on
+// each check reports pass/warn/fail with a hint on how to fix it
+
+use crate::error::ArcadeError;
+use crate::generator::dependencies::{DependencyManager, PackageManager};
+use crate::generator::manifest::{Manifest, MANIFEST_FILE};
+use crate::generator::{GeneratedProject, TemplateGenerator};
+use crate::github::client::resolve_token_with_source;
+use crate::github::GitHubClient;
+use crate::models::Theme;
+use crate::output::Output;
+use crate::update;
+use anyhow::Result;
+use colored::*;
+use serde::Serialize;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Check {
+    pub name: String,
+    pub status: CheckStatus,
+    pub detail: String,
+    pub hint: Option<String>,
+}
+
+impl Check {
+    fn pass(name: &str, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn warn(name: &str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Warn,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn fail(name: &str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Fail,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+pub async fn run(path: &Path, port: Option<u16>, out: &Output) -> Result<()> {
+    out.line("checking your environment...".cyan());
+    out.line("");
+
+    let port = port.unwrap_or_else(|| default_port(path));
+    let checks = run_checks(path, port).await;
+    if !out.is_json() {
+        for check in &checks {
+            print_check(check);
+        }
+    }
+
+    let failed = checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Fail)
+        .count();
+    let warned = checks
+        .iter()
+        .filter(|c| c.status == CheckStatus::Warn)
+        .count();
+
+    out.result(serde_json::json!({
+        "command": "doctor",
+        "ok": failed == 0,
+        "checks": checks,
+        "failed": failed,
+        "warnings": warned,
+    }));
+
+    out.line("");
+    if failed > 0 {
+        return Err(ArcadeError::ChecksFailed { failed, warned }.into());
+    }
+
+    if warned > 0 {
+        out.line(format!("all required checks passed, {} warning(s)", warned).yellow());
+    } else {
+        out.line("everything looks good!".green().bold());
+    }
+
+    Ok(())
+}
+
+pub async fn run_checks(path: &Path, port: u16) -> Vec<Check> {
+    let mut checks = check_templates();
+    checks.push(check_node().await);
+    checks.push(check_package_manager(path).await);
+    checks.push(check_port(port).await);
+    checks.push(check_github_token().await);
+    checks.push(check_updates().await);
+    checks.extend(check_manifest(path));
+    checks
+}
+
+// the dev port of the portfolio being checked, 3000 outside of one
+pub fn default_port(path: &Path) -> u16 {
+    GeneratedProject::open(path)
+        .and_then(|project| project.load_config())
+        .map(|config| config.dev_port)
+        .unwrap_or(3000)
+}
+
+fn print_check(check: &Check) {
+    let label = match check.status {
+        CheckStatus::Pass => "pass".green().bold(),
+        CheckStatus::Warn => "warn".yellow().bold(),
+        CheckStatus::Fail => "fail".red().bold(),
+    };
+
+    println!("  [{}] {}: {}", label, check.name.bold(), check.detail);
+    if let Some(hint) = &check.hint {
+        for line in hint.lines() {
+            println!("         {}", line.bright_black());
+        }
+    }
+}
+
+// the templates directory plus one template per theme
+pub fn check_templates() -> Vec<Check> {
+    let templates_dir = match TemplateGenerator::find_templates_dir() {
+        Ok(dir) => dir,
+        Err(_) => {
+            let tried = TemplateGenerator::template_search_paths()
+                .unwrap_or_default()
+                .iter()
+                .map(|p| format!("tried {}", p.display()))
+                .collect::<Vec<_>>()
+                .join("\n");
+            return vec![Check::fail(
+                "templates",
+                "templates directory not found",
+                format!(
+                    "run from a directory containing templates/, or reinstall with\ncurl -sSfL https://milxzy.github.io/portfolio-arcade/install.sh | bash\n{}",
+                    tried
+                ),
+            )];
+        }
+    };
+
+    let mut checks = vec![Check::pass(
+        "templates",
+        format!("found at {}", templates_dir.display()),
+    )];
+
+    for theme in Theme::available_themes() {
+        let name = format!("{} template", theme.id);
+        let dir = templates_dir.join(&theme.template_dir);
+        if dir.join("package.json").exists() {
+            checks.push(Check::pass(&name, theme.template_dir.clone()));
+        } else {
+            checks.push(Check::fail(
+                &name,
+                format!("{} is missing or incomplete", dir.display()),
+                "reinstall portfolio-arcade to restore the bundled templates",
+            ));
+        }
+    }
+
+    checks
+}
+
+pub async fn check_node() -> Check {
+    match DependencyManager::check_node_available().await {
+        Ok(()) => Check::pass("node.js", "installed"),
+        Err(_) => Check::fail(
+            "node.js",
+            "node is not installed or not on PATH",
+            "install node.js 18 or newer from https://nodejs.org/",
+        ),
+    }
+}
+
+pub async fn check_package_manager(path: &Path) -> Check {
+    let manager = DependencyManager::new(path.to_path_buf());
+    let command = manager.package_manager.command().to_string();
+
+    let detected = match manager.package_manager {
+        PackageManager::Npm => format!("{} (default, no lockfile found)", command),
+        _ => format!("{} (from lockfile)", command),
+    };
+
+    match manager.check_package_manager_available().await {
+        Ok(()) => Check::pass("package manager", detected),
+        Err(_) => Check::warn(
+            "package manager",
+            format!("{} is not installed", detected),
+            match manager.package_manager {
+                PackageManager::Npm => "npm ships with node.js: https://nodejs.org/",
+                PackageManager::Pnpm => "install pnpm: https://pnpm.io/installation",
+                PackageManager::Yarn => "install yarn: https://yarnpkg.com/getting-started",
+            },
+        ),
+    }
+}
+
+pub async fn check_port(port: u16) -> Check {
+    match DependencyManager::check_port_available(port).await {
+        Ok(()) => Check::pass("dev port", format!("{} is free", port)),
+        Err(_) => Check::warn(
+            "dev port",
+            format!("{} is already in use", port),
+            "stop whatever is using it or generate with --port <other port>",
+        ),
+    }
+}
+
+pub async fn check_github_token() -> Check {
+    let (token, source) = match resolve_token_with_source() {
+        Some(resolved) => resolved,
+        None => return Check::warn(
+            "github token",
+            "GITHUB_TOKEN is not set and no token source is configured",
+            "unauthenticated requests are limited to 60/hour; create a token at\nhttps://github.com/settings/tokens and export GITHUB_TOKEN, or set [github] token_command\nin ~/.config/portfolio-arcade/config.toml",
+        ),
+    };
+
+    let client = match GitHubClient::with_token(Some(token)) {
+        Ok(client) => client,
+        Err(e) => {
+            return Check::fail(
+                "github token",
+                format!("the token from {} can't be used: {}", source, e),
+                "make sure the token has no whitespace or newlines",
+            )
+        }
+    };
+
+    match tokio::time::timeout(Duration::from_secs(5), client.authenticated_user()).await {
+        Ok(Ok(login)) => Check::pass(
+            "github token",
+            format!("authenticated as {} with {}", login, source),
+        ),
+        Ok(Err(e)) => Check::fail(
+            "github token",
+            format!("the token from {} couldn't be validated: {}", source, e),
+            "the token may be expired or revoked; create a new one at https://github.com/settings/tokens",
+        ),
+        Err(_) => Check::warn(
+            "github token",
+            format!("couldn't reach github to validate the token from {}", source),
+            "check your network connection",
+        ),
+    }
+}
+
+// only for a generated portfolio: how it was generated and what was edited since
+pub fn check_manifest(path: &Path) -> Option<Check> {
+    GeneratedProject::open(path).ok()?;
+
+    Some(match Manifest::load(path) {
+        Ok(Some(manifest)) => {
+            let edited = manifest.edited_files(path);
+            let mut detail = format!(
+                "generated by {} with the {} theme, {} file(s) edited since",
+                manifest.tool_version,
+                manifest.theme,
+                edited.len()
+            );
+            if !edited.is_empty() {
+                let names: Vec<String> = edited.iter().map(|p| p.display().to_string()).collect();
+                detail.push_str(&format!(": {}", names.join(", ")));
+            }
+            Check::pass("manifest", detail)
+        }
+        Ok(None) => Check::warn(
+            "manifest",
+            format!(
+                "no {}, generated by an older portfolio-arcade",
+                MANIFEST_FILE
+            ),
+            format!(
+                "run `portfolio-arcade upgrade {} --record-base` to record one",
+                path.display()
+            ),
+        ),
+        Err(e) => Check::warn(
+            "manifest",
+            format!("{} can't be read: {}", MANIFEST_FILE, e),
+            format!(
+                "delete it and run `portfolio-arcade upgrade {} --record-base`",
+                path.display()
+            ),
+        ),
+    })
+}
+
+pub async fn check_updates() -> Check {
+    match tokio::time::timeout(Duration::from_secs(3), update::check_for_updates()).await {
+        Ok(Ok(None)) => Check::pass("updates", "you're on the latest version"),
+        Ok(Ok(Some(latest))) => Check::warn(
+            "updates",
+            format!("{} is available", latest),
+            "run `portfolio-arcade update` for instructions",
+        ),
+        _ => Check::warn(
+            "updates",
+            "couldn't check for updates",
+            "check your network connection",
+        ),
+    }
+}
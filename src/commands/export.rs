@@ -0,0 +1,50 @@
+// export command - writes portfolio data in formats other tools understand
+// keeps one source of truth for the portfolio and the resume
+
+use crate::cli::ExportFormat;
+use crate::commands::init::load_config_file;
+use crate::generator::GeneratedProject;
+use crate::models::JsonResume;
+use crate::output::Output;
+use anyhow::Result;
+use colored::*;
+use std::fs;
+use std::path::Path;
+
+pub fn run(
+    format: ExportFormat,
+    path: &Path,
+    config: Option<&Path>,
+    output: &Path,
+    out: &Output,
+) -> Result<()> {
+    // a config file wins over the generated project's portfolio.json
+    let config = match config {
+        Some(file) => load_config_file(file)?,
+        None => GeneratedProject::open(path)?.load_config()?,
+    };
+
+    let content = match format {
+        ExportFormat::JsonResume => {
+            serde_json::to_string_pretty(&JsonResume::from_config(&config))?
+        }
+    };
+
+    fs::write(output, content)?;
+
+    out.line(
+        format!(
+            "exported {} projects to {}",
+            config.projects.len(),
+            output.display()
+        )
+        .green(),
+    );
+    out.result(serde_json::json!({
+        "command": "export",
+        "format": "json-resume",
+        "path": output,
+        "project_count": config.projects.len(),
+    }));
+    Ok(())
+}
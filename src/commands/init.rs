@@ -0,0 +1,339 @@
+// init command - interactive tui or headless generation from flags
+// headless mode lets scripts and ci scaffold portfolios without a terminal
+
+use crate::cli::InitArgs;
+use crate::error::ArcadeError;
+use crate::generator::all_themes::{AllThemesGenerator, LANDING_PAGE};
+use crate::generator::plan::{FileAction, GenerationReport, Outcome};
+use crate::generator::TemplateGenerator;
+use crate::models::{Cartridge, JsonResume, PortfolioConfig, Post, UserDefaults, POSTS_DIR};
+use crate::output::Output;
+use crate::tui::App;
+use crate::utils::json;
+use crate::utils::validation::{validate_config, validate_project_name};
+use anyhow::{Context, Result};
+use colored::*;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub async fn run(args: InitArgs, out: &Output) -> Result<()> {
+    // json output can't share the terminal with the tui
+    if !args.is_headless() && !out.is_json() {
+        // launch the interactive tui for project configuration
+        let app = App::new(args.project_name, UserDefaults::load()?);
+        return app.run().await;
+    }
+
+    let project_name = args
+        .project_name
+        .clone()
+        .unwrap_or_else(|| "my-portfolio".to_string());
+    validate_project_name(&project_name)
+        .map_err(|e| ArcadeError::InvalidConfig(format!("project_name: {}", e)))?;
+
+    // a cartridge brings its own config and the images it references
+    let cartridge = match &args.from {
+        Some(path) => Some(Cartridge::read(path)?),
+        None => None,
+    };
+    let (config, warnings) = build_config(&args, cartridge.as_ref()).await?;
+    validate_config(&config).map_err(ArcadeError::InvalidConfig)?;
+    for warning in &warnings {
+        out.warn(warning);
+    }
+
+    let assets = match &cartridge {
+        Some(cartridge) if !cartridge.assets.is_empty() => {
+            let dir = tempfile::tempdir()?;
+            cartridge.write_assets(dir.path())?;
+            Some(dir)
+        }
+        _ => None,
+    };
+    let assets_dir = assets.as_ref().map(|dir| dir.path().to_path_buf());
+
+    if args.all_themes {
+        return generate_all_themes(&args, project_name, config, assets_dir, &warnings, out).await;
+    }
+
+    let mut generator = TemplateGenerator::with_existing_policy(
+        project_name.clone(),
+        config,
+        std::env::current_dir()?.join(&project_name),
+        args.force.then_some(args.existing),
+    )?;
+    generator.assets_dir = assets_dir;
+    if args.dry_run {
+        return report_dry_run(&generator, &warnings, out);
+    }
+    let report = generator.generate().await?;
+
+    out.line("portfolio generated successfully!".green().bold());
+    out.line("");
+    out.line(format!("  {}: ./{}/", "location".bold(), project_name));
+    out.line(format!(
+        "  {}: {}",
+        "theme".bold(),
+        generator.theme.display_name
+    ));
+    out.line(format!(
+        "  {}: {}",
+        "projects".bold(),
+        generator.config.projects.len()
+    ));
+    print_existing_files(&report, out);
+    out.line("");
+    out.line("next steps:".bold());
+    out.line(format!("  cd {}", project_name));
+    out.line("  npm install");
+    out.line("  npm run dev");
+
+    out.result(serde_json::json!({
+        "command": "init",
+        "project_name": project_name,
+        "path": generator.target_dir,
+        "theme": generator.theme.id,
+        "project_count": generator.config.projects.len(),
+        "files": report,
+        "warnings": warnings,
+    }));
+
+    Ok(())
+}
+
+// one directory per theme under the project directory, plus the landing page
+async fn generate_all_themes(
+    args: &InitArgs,
+    project_name: String,
+    config: PortfolioConfig,
+    assets_dir: Option<PathBuf>,
+    warnings: &[String],
+    out: &Output,
+) -> Result<()> {
+    let mut generator = AllThemesGenerator::new(
+        project_name.clone(),
+        config,
+        std::env::current_dir()?.join(&project_name),
+        args.force.then_some(args.existing),
+    )?;
+    generator.assets_dir = assets_dir;
+    let generated = generator.generate().await?;
+    let outputs = &generated.themes;
+
+    out.line("portfolio generated in every theme!".green().bold());
+    out.line("");
+    out.line(format!(
+        "  {}: ./{}/{}",
+        "landing page".bold(),
+        project_name,
+        LANDING_PAGE
+    ));
+    print_existing_files(&generated.landing_page, out);
+    for output in outputs {
+        out.line(format!(
+            "  {}: ./{}/{}/",
+            output.theme.display_name.bold(),
+            project_name,
+            output.theme.id
+        ));
+        print_existing_files(&output.report, out);
+    }
+    out.line(format!(
+        "  {}: {}",
+        "projects".bold(),
+        generator.config.projects.len()
+    ));
+    out.line("");
+    out.line("next steps:".bold());
+    out.line(format!("  cd {}/<theme>", project_name));
+    out.line("  npm install");
+    out.line("  npm run dev");
+
+    let themes: Vec<Value> = outputs
+        .iter()
+        .map(|output| {
+            serde_json::json!({
+                "theme": output.theme.id,
+                "path": output.path,
+                "files": output.report,
+            })
+        })
+        .collect();
+    out.result(serde_json::json!({
+        "command": "init",
+        "project_name": project_name,
+        "path": generator.target_dir,
+        "landing_page": generator.target_dir.join(LANDING_PAGE),
+        "landing_page_files": generated.landing_page,
+        "themes": themes,
+        "project_count": generator.config.projects.len(),
+        "warnings": warnings,
+    }));
+
+    Ok(())
+}
+
+// lists the files --force touched or left alone in an existing directory
+fn print_existing_files(report: &GenerationReport, out: &Output) {
+    for (label, files) in [
+        ("replaced", &report.replaced),
+        ("merged", &report.merged),
+        ("kept", &report.skipped),
+    ] {
+        if files.is_empty() {
+            continue;
+        }
+        out.line(format!("  {} ({}):", label.bold(), files.len()));
+        for file in files {
+            out.line(format!("    {}", file.display()));
+        }
+    }
+}
+
+// lists what generation would write, reading the template but writing nothing
+fn report_dry_run(generator: &TemplateGenerator, warnings: &[String], out: &Output) -> Result<()> {
+    let plan = generator.plan()?;
+    // with --force on an existing directory, show what the policy would do to each file
+    let outcomes = match generator.existing {
+        Some(policy) if generator.target_dir.exists() => {
+            Some(plan.resolve(&generator.target_dir, policy)?)
+        }
+        _ => None,
+    };
+    let outcome = |index: usize| outcomes.as_ref().map(|o| o[index].outcome);
+    let data_path = Path::new("public").join("data").join("portfolio.json");
+    let portfolio_data: Value = match plan.generated(&data_path) {
+        Some(content) => serde_json::from_str(content)?,
+        None => Value::Null,
+    };
+
+    out.line(
+        format!(
+            "dry run: {} files would be generated in {}",
+            plan.files.len(),
+            generator.target_dir.display()
+        )
+        .cyan()
+        .bold(),
+    );
+    out.line("");
+    for (index, file) in plan.files.iter().enumerate() {
+        let action = match file.action() {
+            FileAction::Copy => "copy ".bright_black(),
+            FileAction::Write => "write".green(),
+        };
+        match outcome(index) {
+            Some(outcome) => out.line(format!(
+                "  {} {} ({})",
+                action,
+                file.path.display(),
+                format!("{:?}", outcome).to_lowercase()
+            )),
+            None => out.line(format!("  {} {}", action, file.path.display())),
+        }
+    }
+    out.line("");
+    out.line(format!("{}:", data_path.display()).bold());
+    out.line(serde_json::to_string_pretty(&portfolio_data)?);
+
+    let files: Vec<Value> = plan
+        .files
+        .iter()
+        .enumerate()
+        .map(|(index, file)| {
+            serde_json::json!({
+                "path": file.path,
+                "action": file.action(),
+                "outcome": outcome(index).unwrap_or(Outcome::Create),
+            })
+        })
+        .collect();
+    out.result(serde_json::json!({
+        "command": "init",
+        "dry_run": true,
+        "path": generator.target_dir,
+        "theme": generator.theme.id,
+        "project_count": generator.config.projects.len(),
+        "files": files,
+        "portfolio_data": portfolio_data,
+        "warnings": warnings,
+    }));
+
+    Ok(())
+}
+
+// builds the config from the cartridge, or the optional config file and json resume,
+// then applies flag overrides
+// returns warnings for anything that fell back to placeholder data
+pub async fn build_config(
+    args: &InitArgs,
+    cartridge: Option<&Cartridge>,
+) -> Result<(PortfolioConfig, Vec<String>)> {
+    let mut config = match (cartridge, &args.config) {
+        // used as packed so the site comes out identical
+        (Some(cartridge), _) => cartridge.config.clone(),
+        (None, Some(path)) => merge_config_file(path, &UserDefaults::load()?.config())?,
+        (None, None) => UserDefaults::load()?.config(),
+    };
+    let mut warnings = Vec::new();
+
+    if let Some(path) = &args.from_json_resume {
+        let resume = JsonResume::load(path)?;
+        let skipped = resume.skipped_sections();
+        if !skipped.is_empty() {
+            warnings.push(format!(
+                "{} has sections a portfolio can't show, they were skipped: {}",
+                path.display(),
+                skipped.join(", ")
+            ));
+        }
+        config = resume.into_config(config);
+    }
+
+    if let Some(theme) = &args.theme {
+        config.theme = theme.clone();
+    }
+    if let Some(cms) = &args.cms {
+        config.cms = cms.clone();
+    }
+    if let Some(port) = args.port {
+        config.dev_port = port;
+    }
+    if !args.repos.is_empty() {
+        let imported = crate::github::projects::urls_to_projects(&args.repos).await;
+        config.add_skills(imported.skills());
+        config.projects = imported.projects;
+        warnings.extend(imported.warnings);
+    }
+
+    Ok((config, warnings))
+}
+
+// reads a portfolio config file, filling missing fields from the user defaults
+pub fn load_config_file(path: &Path) -> Result<PortfolioConfig> {
+    merge_config_file(path, &UserDefaults::load()?.config())
+}
+
+// reads a portfolio config file as written, missing fields get the built-in placeholders
+pub fn load_config_file_as_written(path: &Path) -> Result<PortfolioConfig> {
+    merge_config_file(path, &PortfolioConfig::default())
+}
+
+// layers a config file over a base config, field by field
+fn merge_config_file(path: &Path, base: &PortfolioConfig) -> Result<PortfolioConfig> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("failed to read config file {}", path.display()))?;
+    let invalid =
+        |e: serde_json::Error| ArcadeError::InvalidConfig(format!("{}: {}", path.display(), e));
+    let file: Value = serde_json::from_str(&content).map_err(invalid)?;
+
+    let mut merged = serde_json::to_value(base)?;
+    json::merge(&mut merged, file);
+    let mut config: PortfolioConfig = serde_json::from_value(merged).map_err(invalid)?;
+
+    // posts written as markdown next to the config file join the ones it lists
+    let dir = path.parent().unwrap_or(Path::new("."));
+    config.add_posts(Post::load_dir(&dir.join(POSTS_DIR))?);
+    Ok(config)
+}
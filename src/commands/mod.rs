@@ -0,0 +1,15 @@
+// command implementations behind the cli subcommands
+// each module handles one subcommand end to end
+
+pub mod batch;
+pub mod build;
+pub mod cartridge;
+pub mod deploy;
+pub mod doctor;
+pub mod export;
+pub mod init;
+pub mod projects;
+pub mod serve;
+pub mod switch_theme;
+pub mod sync;
+pub mod upgrade;
@@ -0,0 +1,130 @@
+// add-project and remove-project commands for generated portfolios
+// github urls go through the same service the tui uses
+
+use crate::cli::AddProjectArgs;
+use crate::generator::GeneratedProject;
+use crate::github::{projects, GitHubClient};
+use crate::models::Project;
+use crate::output::Output;
+use crate::utils::validation::validate_url;
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::path::Path;
+
+pub async fn add(args: AddProjectArgs, out: &Output) -> Result<()> {
+    let mut portfolio = GeneratedProject::open(&args.path)?;
+    let mut config = portfolio.load_config()?;
+    let index = projects::next_project_index(&config.projects);
+    let mut warnings = Vec::new();
+
+    let mut project = match &args.url {
+        Some(url) => {
+            crate::github::parse_github_url(url)?;
+            let imported = match GitHubClient::new() {
+                Ok(client) => projects::url_to_project(&client, url, index).await,
+                Err(e) => projects::ImportedProject {
+                    project: projects::placeholder_project(url, index),
+                    languages: Default::default(),
+                    warning: Some(format!(
+                        "failed to create GitHub client ({}), using placeholder project",
+                        e
+                    )),
+                },
+            };
+            warnings.extend(imported.warning.clone());
+            // languages new to the portfolio become skills
+            config.add_skills(imported.skills());
+            imported.project
+        }
+        None => {
+            let title = args
+                .title
+                .clone()
+                .ok_or_else(|| anyhow!("--title is required when no github url is given"))?;
+            Project {
+                id: format!("project-{}", index + 1),
+                title,
+                category: "Development".to_string(),
+                ..Default::default()
+            }
+        }
+    };
+
+    // flags win over whatever github reported
+    if let Some(title) = args.title {
+        project.title = title;
+    }
+    if let Some(description) = args.description {
+        project.description = description;
+    }
+    if !args.tech.is_empty() {
+        project.tech_stack = args.tech;
+    }
+    if let Some(live) = args.live {
+        validate_url(&live).map_err(|e| anyhow!("--live: {}", e))?;
+        project.links.live = Some(live);
+    }
+    if args.featured {
+        project.featured = true;
+    }
+    if project.full_description.is_empty() {
+        project.full_description = project.description.clone();
+    }
+
+    for warning in &warnings {
+        out.warn(warning);
+    }
+    let (id, title) = (project.id.clone(), project.title.clone());
+    config.projects.push(project);
+    portfolio.save_config(&config)?;
+
+    out.line(format!("{} {} ({})", "added".green(), title, id));
+    out.result(serde_json::json!({
+        "command": "add-project",
+        "id": id,
+        "title": title,
+        "project_count": config.projects.len(),
+        "warnings": warnings,
+    }));
+
+    Ok(())
+}
+
+pub fn remove(path: &Path, id: &str, out: &Output) -> Result<()> {
+    let mut portfolio = GeneratedProject::open(path)?;
+    let mut config = portfolio.load_config()?;
+
+    let position = config
+        .projects
+        .iter()
+        .position(|p| p.id == id)
+        .ok_or_else(|| {
+            let ids: Vec<&str> = config.projects.iter().map(|p| p.id.as_str()).collect();
+            anyhow!(
+                "no project with id '{}' (available: {})",
+                id,
+                if ids.is_empty() {
+                    "none".to_string()
+                } else {
+                    ids.join(", ")
+                }
+            )
+        })?;
+
+    let removed = config.projects.remove(position);
+    portfolio.save_config(&config)?;
+
+    out.line(format!(
+        "{} {} ({})",
+        "removed".yellow(),
+        removed.title,
+        removed.id
+    ));
+    out.result(serde_json::json!({
+        "command": "remove-project",
+        "id": removed.id,
+        "title": removed.title,
+        "project_count": config.projects.len(),
+    }));
+    Ok(())
+}
@@ -0,0 +1,223 @@
+// serve command - previews a built portfolio without node
+// a small http server over the static export in out/
+
+use crate::commands::build::OUTPUT_DIR;
+use crate::generator::GeneratedProject;
+use crate::output::Output;
+use anyhow::{anyhow, Context, Result};
+use colored::*;
+use std::net::SocketAddr;
+use std::path::{Component, Path, PathBuf};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+// requests bigger than this are rejected, we only need the request line
+const MAX_REQUEST_HEAD: usize = 8 * 1024;
+
+pub async fn run(path: &Path, port: Option<u16>, out: &Output) -> Result<()> {
+    let portfolio = GeneratedProject::open(path)?;
+    let root = portfolio.root.join(OUTPUT_DIR);
+    if !root.is_dir() {
+        return Err(anyhow!(
+            "{} doesn't exist yet, run `portfolio-arcade build` first",
+            root.display()
+        ));
+    }
+
+    let port = match port {
+        Some(port) => port,
+        None => portfolio.load_config()?.dev_port,
+    };
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("couldn't listen on port {}", port))?;
+
+    let url = format!("http://localhost:{}", port);
+    out.line(format!("serving {} at {}", root.display(), url.bold()).green());
+    out.line("press ctrl+c to stop".bright_black());
+    out.line("");
+    out.result(serde_json::json!({
+        "command": "serve",
+        "root": root,
+        "url": url,
+    }));
+
+    tokio::select! {
+        result = serve(listener, root, *out) => result,
+        _ = tokio::signal::ctrl_c() => {
+            out.line("");
+            out.line("stopped".yellow());
+            Ok(())
+        }
+    }
+}
+
+// accepts connections until the listener fails
+pub async fn serve(listener: TcpListener, root: PathBuf, out: Output) -> Result<()> {
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let root = root.clone();
+        tokio::spawn(async move {
+            // a dropped connection only affects that request
+            let _ = handle_connection(stream, &root, out).await;
+        });
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream, root: &Path, out: Output) -> Result<()> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 1024];
+    while !buffer.windows(4).any(|w| w == b"\r\n\r\n") {
+        let read = stream.read(&mut chunk).await?;
+        if read == 0 {
+            return Ok(());
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+        if buffer.len() > MAX_REQUEST_HEAD {
+            return respond(&mut stream, 431, "text/plain", b"request too large", true).await;
+        }
+    }
+
+    let head = String::from_utf8_lossy(&buffer);
+    let mut parts = head.lines().next().unwrap_or_default().split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or("/").to_string();
+
+    if method != "GET" && method != "HEAD" {
+        return respond(&mut stream, 405, "text/plain", b"method not allowed", true).await;
+    }
+    let with_body = method == "GET";
+
+    let (status, file) = match resolve(root, &target) {
+        Some(file) => (200, Some(file)),
+        None => (
+            404,
+            Some(root.join("404.html")).filter(|page| page.is_file()),
+        ),
+    };
+    out.line(format!("{} {} {}", method, target, status).bright_black());
+
+    match file {
+        Some(file) => {
+            let body = tokio::fs::read(&file).await?;
+            respond(&mut stream, status, mime_type(&file), &body, with_body).await
+        }
+        None => respond(&mut stream, 404, "text/plain", b"404 not found", with_body).await,
+    }
+}
+
+async fn respond(
+    stream: &mut TcpStream,
+    status: u16,
+    content_type: &str,
+    body: &[u8],
+    with_body: bool,
+) -> Result<()> {
+    let reason = match status {
+        200 => "OK",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        431 => "Request Header Fields Too Large",
+        _ => "Error",
+    };
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        reason,
+        content_type,
+        body.len()
+    );
+
+    stream.write_all(head.as_bytes()).await?;
+    if with_body {
+        stream.write_all(body).await?;
+    }
+    stream.shutdown().await?;
+    Ok(())
+}
+
+// maps a request target onto a file in the export
+// clean urls resolve to <path>/index.html or <path>.html like a static host would
+pub fn resolve(root: &Path, target: &str) -> Option<PathBuf> {
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+    let decoded = percent_decode(path)?;
+
+    // never serve anything outside the export
+    let relative = Path::new(decoded.trim_start_matches('/'));
+    if relative
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_)))
+    {
+        return None;
+    }
+
+    let candidate = root.join(relative);
+    if candidate.is_file() {
+        return Some(candidate);
+    }
+    if candidate.join("index.html").is_file() {
+        return Some(candidate.join("index.html"));
+    }
+
+    let with_extension = candidate.with_extension("html");
+    if !decoded.ends_with('/') && with_extension.is_file() {
+        return Some(with_extension);
+    }
+
+    None
+}
+
+fn percent_decode(input: &str) -> Option<String> {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = input.get(i + 1..i + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+pub fn mime_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "html" | "htm" => "text/html; charset=utf-8",
+        "css" => "text/css; charset=utf-8",
+        "js" | "mjs" => "text/javascript; charset=utf-8",
+        "json" | "map" => "application/json",
+        "txt" => "text/plain; charset=utf-8",
+        "md" => "text/markdown; charset=utf-8",
+        "xml" => "application/xml",
+        "yml" | "yaml" => "text/yaml; charset=utf-8",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "ico" => "image/x-icon",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "ttf" => "font/ttf",
+        "otf" => "font/otf",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "ogg" => "audio/ogg",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "pdf" => "application/pdf",
+        "wasm" => "application/wasm",
+        _ => "application/octet-stream",
+    }
+}
@@ -0,0 +1,112 @@
+// switch-theme command - regenerates a portfolio with a different console theme
+// the canonical config is recovered from portfolio.json so manual edits carry over
+
+use crate::error::ArcadeError;
+use crate::generator::manifest::Manifest;
+use crate::generator::{GeneratedProject, TemplateGenerator};
+use crate::models::Theme;
+use crate::output::Output;
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::path::{Path, PathBuf};
+
+// user-owned folders that are carried over to the new theme as-is
+const CARRIED_DIRS: &[&str] = &["content", "public/projects", "public/images"];
+
+pub async fn run(path: &Path, theme_id: &str, output: Option<PathBuf>, out: &Output) -> Result<()> {
+    let source = GeneratedProject::open(path)?;
+    let theme = Theme::find_by_id(theme_id).ok_or_else(|| {
+        ArcadeError::InvalidConfig(format!(
+            "theme: theme '{}' not found (expected ps3, ps5 or wii)",
+            theme_id
+        ))
+    })?;
+
+    if theme.id == source.theme.id {
+        return Err(anyhow!("portfolio already uses the {} theme", theme.id));
+    }
+
+    let mut config = source.load_config()?;
+    config.theme = theme.id.clone();
+
+    let target_dir = match output {
+        Some(dir) => dir,
+        None => default_output_dir(&source, &theme)?,
+    };
+
+    let generator = TemplateGenerator::with_target_dir(source.name(), config.clone(), target_dir)?;
+    generator.generate().await?;
+
+    let mut carried = Vec::new();
+    for dir in CARRIED_DIRS {
+        let from = source.root.join(dir);
+        if from.is_dir() {
+            let options = fs_extra::dir::CopyOptions::new()
+                .overwrite(true)
+                .content_only(true);
+            fs_extra::dir::copy(&from, generator.target_dir.join(dir), &options)?;
+            for entry in walkdir::WalkDir::new(&from) {
+                let entry = entry?;
+                if entry.file_type().is_file() {
+                    carried.push(Path::new(dir).join(entry.path().strip_prefix(&from)?));
+                }
+            }
+        }
+    }
+
+    // the carried files are part of the new portfolio as generated, not edits made after it
+    if let Some(mut manifest) = Manifest::load(&generator.target_dir)? {
+        for path in &carried {
+            manifest.record(path, &std::fs::read(generator.target_dir.join(path))?);
+        }
+        manifest.save(&generator.target_dir)?;
+    }
+
+    // keep any top-level keys added to portfolio.json by hand
+    let mut switched = GeneratedProject::open(&generator.target_dir)?;
+    if let (Some(old), Some(new)) = (source.data.as_object(), switched.data.as_object_mut()) {
+        for (key, value) in old {
+            new.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+    }
+    switched.save_config(&config)?;
+
+    out.line(
+        format!(
+            "switched from {} to {}",
+            source.theme.display_name, theme.display_name
+        )
+        .green()
+        .bold(),
+    );
+    out.line(format!(
+        "  {}: {}",
+        "location".bold(),
+        generator.target_dir.display()
+    ));
+    out.line(format!(
+        "  {}: {}",
+        "projects".bold(),
+        config.projects.len()
+    ));
+
+    out.result(serde_json::json!({
+        "command": "switch-theme",
+        "from_theme": source.theme.id,
+        "theme": theme.id,
+        "path": generator.target_dir,
+        "project_count": config.projects.len(),
+    }));
+
+    Ok(())
+}
+
+// <dir>-<theme> next to the source portfolio
+fn default_output_dir(source: &GeneratedProject, theme: &Theme) -> Result<PathBuf> {
+    let root = source.root.canonicalize()?;
+    let name = source
+        .dir_name()
+        .ok_or_else(|| anyhow!("can't derive an output directory, pass --output"))?;
+    let parent = root.parent().unwrap_or(&root);
+    Ok(parent.join(format!("{}-{}", name, theme.id)))
+}
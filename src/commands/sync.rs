@@ -0,0 +1,102 @@
+// sync command - refreshes github data in an already generated portfolio
+// stars, forks, topics and descriptions are re-fetched and run back through the theme adapter
+// languages new to the portfolio are added as skills, like init seeds them
+// posts edited in content/posts/ are copied into portfolio.json on the way
+
+use crate::generator::GeneratedProject;
+use crate::github::{projects::refresh_project, skills_from_languages, GitHubClient};
+use crate::output::Output;
+use anyhow::Result;
+use colored::*;
+use std::collections::HashMap;
+use std::path::Path;
+
+pub async fn run(path: &Path, out: &Output) -> Result<()> {
+    let mut project = GeneratedProject::open(path)?;
+    let mut config = project.load_config()?;
+    let client = GitHubClient::new()?;
+
+    out.line(format!("syncing {} projects from github...", config.projects.len()).cyan());
+
+    let mut refreshed = 0;
+    let mut results = Vec::new();
+    let mut warnings = Vec::new();
+    let mut languages: HashMap<String, u64> = HashMap::new();
+    for item in config.projects.iter_mut() {
+        let status = match refresh_project(&client, item).await {
+            Ok(Some(repo_languages)) => {
+                refreshed += 1;
+                for (language, bytes) in repo_languages {
+                    *languages.entry(language).or_default() += bytes;
+                }
+                out.line(format!("  {} {}", "refreshed".green(), item.id));
+                "refreshed"
+            }
+            Ok(None) => {
+                out.line(format!(
+                    "  {} {} (no github link)",
+                    "skipped".yellow(),
+                    item.id
+                ));
+                "skipped"
+            }
+            Err(e) => {
+                // keep the existing data rather than dropping the project
+                let warning = format!("failed to refresh {}: {}", item.id, e);
+                out.warn(&warning);
+                warnings.push(warning);
+                "failed"
+            }
+        };
+        results.push(serde_json::json!({ "id": item.id, "status": status }));
+    }
+
+    // skills the user already listed keep their category and proficiency
+    let skill_count = config.skills.len();
+    config.add_skills(skills_from_languages(&languages));
+    let skills_added = config.skills.len() - skill_count;
+
+    // posts written in content/posts/ since the last save are picked up too
+    let posts_changed = project.posts_changed(&config);
+    if refreshed > 0 || skills_added > 0 || posts_changed {
+        project.save_config(&config)?;
+    }
+
+    out.line("");
+    out.line(
+        format!(
+            "updated {} of {} projects in {}",
+            refreshed,
+            config.projects.len(),
+            project.data_path().display()
+        )
+        .green(),
+    );
+    if skills_added > 0 {
+        out.line(format!("added {} skill(s) from repo languages", skills_added).green());
+    }
+    if posts_changed {
+        out.line(
+            format!(
+                "picked up {} post(s) from content/posts",
+                config.posts.len()
+            )
+            .green(),
+        );
+    }
+
+    out.result(serde_json::json!({
+        "command": "sync",
+        "path": project.root,
+        "theme": project.theme.id,
+        "project_count": config.projects.len(),
+        "refreshed": refreshed,
+        "projects": results,
+        "skills_added": skills_added,
+        "post_count": config.posts.len(),
+        "posts_updated": posts_changed,
+        "warnings": warnings,
+    }));
+
+    Ok(())
+}
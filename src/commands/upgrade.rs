@@ -0,0 +1,152 @@
+// upgrade command - moves a generated portfolio onto the bundled template
+// template fixes come in, the user's own edits stay, overlapping changes get conflict markers
+
+use crate::generator::manifest::{Manifest, MANIFEST_FILE};
+use crate::generator::plan::{ExistingFiles, GenerationPlan};
+use crate::generator::upgrade::{
+    apply_upgrade, plan_upgrade, TemplateBase, UpgradeReport, BASE_FILE,
+};
+use crate::generator::{GeneratedProject, TemplateGenerator};
+use crate::models::PortfolioConfig;
+use crate::output::Output;
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::path::Path;
+
+pub async fn run(path: &Path, dry_run: bool, out: &Output) -> Result<()> {
+    let portfolio = GeneratedProject::open(path)?;
+    let base = TemplateBase::load(&portfolio.root)?.ok_or_else(|| {
+        anyhow!(
+            "{} has no {}, it was generated before upgrades were supported\n{}",
+            portfolio.root.display(),
+            BASE_FILE,
+            record_base_hint(&portfolio.root)
+        )
+    })?;
+
+    let (config, new_plan) = regenerate(&portfolio)?;
+
+    let steps = plan_upgrade(&portfolio.root, &base, &new_plan)?;
+    let report = UpgradeReport::from_steps(&steps);
+
+    if !dry_run {
+        apply_upgrade(&portfolio.root, &steps)?;
+        TemplateBase::from_plan(&new_plan)?.save(&portfolio.root)?;
+        Manifest::new(&config, &new_plan)?.save(&portfolio.root)?;
+    }
+
+    if report.is_empty() {
+        out.line("already on the latest template".green());
+    } else {
+        let verb = if dry_run { "would upgrade" } else { "upgraded" };
+        out.line(
+            format!("{} {}", verb, portfolio.root.display())
+                .green()
+                .bold(),
+        );
+        for (label, files) in [
+            ("updated", &report.updated),
+            ("added", &report.added),
+            ("removed", &report.removed),
+            ("merged", &report.merged),
+            ("conflicts", &report.conflicts),
+            ("kept (couldn't merge)", &report.kept),
+        ] {
+            if files.is_empty() {
+                continue;
+            }
+            out.line(format!("  {} ({}):", label.bold(), files.len()));
+            for file in files {
+                out.line(format!("    {}", file.display()));
+            }
+        }
+    }
+
+    if !report.conflicts.is_empty() {
+        out.warn(format!(
+            "{} file(s) have conflict markers (<<<<<<< ours / >>>>>>> theirs) to resolve",
+            report.conflicts.len()
+        ));
+    }
+
+    out.result(serde_json::json!({
+        "command": "upgrade",
+        "path": portfolio.root,
+        "dry_run": dry_run,
+        "files": report,
+    }));
+
+    Ok(())
+}
+
+// adopts the bundled template for a portfolio generated before bases and manifests existed
+// only the missing base and manifest are written, so later upgrades treat every
+// difference from today's template as the user's own edit
+pub fn record_base(path: &Path, out: &Output) -> Result<()> {
+    let portfolio = GeneratedProject::open(path)?;
+    let has_base = TemplateBase::load(&portfolio.root)?.is_some();
+    let has_manifest = Manifest::load(&portfolio.root).ok().flatten().is_some();
+    if has_base && has_manifest {
+        return Err(anyhow!(
+            "{} already has a {} and a {}, run `portfolio-arcade upgrade` to use them",
+            portfolio.root.display(),
+            BASE_FILE,
+            MANIFEST_FILE
+        ));
+    }
+
+    let (config, plan) = regenerate(&portfolio)?;
+    let mut recorded = Vec::new();
+    if !has_base {
+        TemplateBase::from_plan(&plan)?.save(&portfolio.root)?;
+        recorded.push(BASE_FILE);
+    }
+    if !has_manifest {
+        Manifest::new(&config, &plan)?.save(&portfolio.root)?;
+        recorded.push(MANIFEST_FILE);
+    }
+
+    out.line(
+        format!(
+            "recorded {} from the {} template",
+            recorded.join(" and "),
+            portfolio.theme.id
+        )
+        .green()
+        .bold(),
+    );
+    out.line(format!(
+        "  {}: {}",
+        "location".bold(),
+        portfolio.root.display()
+    ));
+    out.result(serde_json::json!({
+        "command": "upgrade",
+        "path": portfolio.root,
+        "record_base": true,
+        "recorded": recorded,
+    }));
+
+    Ok(())
+}
+
+// the command that records a base for the portfolio at root, for error messages and hints
+pub fn record_base_hint(root: &Path) -> String {
+    format!(
+        "run `portfolio-arcade upgrade {} --record-base` to record the current template as its base",
+        root.display()
+    )
+}
+
+// works out in memory what the bundled template generates from the portfolio's own data
+fn regenerate(portfolio: &GeneratedProject) -> Result<(PortfolioConfig, GenerationPlan)> {
+    let config = portfolio.load_config()?;
+    let generator = TemplateGenerator::with_existing_policy(
+        portfolio.name(),
+        config.clone(),
+        portfolio.root.clone(),
+        Some(ExistingFiles::Overwrite),
+    )?;
+    let plan = generator.plan()?;
+    Ok((config, plan))
+}
@@ -0,0 +1,64 @@
+// typed failures that map onto stable process exit codes
+// anything else exits with the generic failure code
+
+use std::path::PathBuf;
+use thiserror::Error;
+
+// exit codes are part of the cli contract, scripts depend on them
+// 0 is success and 2 is a usage error reported by clap
+pub mod exit_code {
+    pub const FAILURE: i32 = 1;
+    pub const INVALID_CONFIG: i32 = 3;
+    pub const TEMPLATE_MISSING: i32 = 4;
+    pub const TARGET_EXISTS: i32 = 5;
+    pub const GITHUB: i32 = 6;
+    pub const NOT_A_PORTFOLIO: i32 = 7;
+    pub const CHECKS_FAILED: i32 = 8;
+    pub const BATCH_FAILED: i32 = 9;
+}
+
+#[derive(Debug, Error)]
+pub enum ArcadeError {
+    #[error("invalid portfolio config: {0}")]
+    InvalidConfig(String),
+
+    #[error("{0}")]
+    TemplateMissing(String),
+
+    #[error("directory '{}' already exists (pass --force to generate into it)", .0.display())]
+    TargetExists(PathBuf),
+
+    #[error("{0}")]
+    GitHub(String),
+
+    #[error("{0}")]
+    NotAPortfolio(String),
+
+    #[error("{failed} check(s) failed, {warned} warning(s)")]
+    ChecksFailed { failed: usize, warned: usize },
+
+    #[error("{failed} of {total} portfolio(s) failed to generate")]
+    BatchFailed { failed: usize, total: usize },
+}
+
+impl ArcadeError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ArcadeError::InvalidConfig(_) => exit_code::INVALID_CONFIG,
+            ArcadeError::TemplateMissing(_) => exit_code::TEMPLATE_MISSING,
+            ArcadeError::TargetExists(_) => exit_code::TARGET_EXISTS,
+            ArcadeError::GitHub(_) => exit_code::GITHUB,
+            ArcadeError::NotAPortfolio(_) => exit_code::NOT_A_PORTFOLIO,
+            ArcadeError::ChecksFailed { .. } => exit_code::CHECKS_FAILED,
+            ArcadeError::BatchFailed { .. } => exit_code::BATCH_FAILED,
+        }
+    }
+}
+
+// finds the most specific exit code anywhere in the error chain
+pub fn exit_code_for(err: &anyhow::Error) -> i32 {
+    err.chain()
+        .find_map(|cause| cause.downcast_ref::<ArcadeError>())
+        .map(ArcadeError::exit_code)
+        .unwrap_or(exit_code::FAILURE)
+}
@@ -0,0 +1,199 @@
+// one portfolio config generated in every theme, side by side
+// each theme gets its own directory and an index.html lets visitors pick one
+
+use crate::error::ArcadeError;
+use crate::generator::plan::{ExistingFiles, GenerationPlan, GenerationReport};
+use crate::generator::TemplateGenerator;
+use crate::models::{PortfolioConfig, Theme};
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+pub const LANDING_PAGE: &str = "index.html";
+
+pub struct AllThemesGenerator {
+    pub project_name: String,
+    pub config: PortfolioConfig,
+    // holds one directory per theme plus the landing page
+    pub target_dir: PathBuf,
+    pub existing: Option<ExistingFiles>,
+    // copied over every theme's public/, see TemplateGenerator::assets_dir
+    pub assets_dir: Option<PathBuf>,
+}
+
+// one theme's portfolio inside the target directory
+#[derive(Debug, Clone)]
+pub struct ThemeOutput {
+    pub theme: Theme,
+    pub path: PathBuf,
+    pub report: GenerationReport,
+}
+
+#[derive(Debug, Clone)]
+pub struct AllThemesOutput {
+    pub themes: Vec<ThemeOutput>,
+    // what happened to index.html, it follows the same policy as the theme files
+    pub landing_page: GenerationReport,
+}
+
+impl AllThemesGenerator {
+    pub fn new(
+        project_name: String,
+        config: PortfolioConfig,
+        target_dir: PathBuf,
+        existing: Option<ExistingFiles>,
+    ) -> Result<Self> {
+        if target_dir.exists() && existing.is_none() {
+            return Err(ArcadeError::TargetExists(target_dir).into());
+        }
+        Ok(Self {
+            project_name,
+            config,
+            target_dir,
+            existing,
+            assets_dir: None,
+        })
+    }
+
+    // a fresh target is assembled in a staging directory and renamed into place,
+    // like a single-theme generation
+    pub async fn generate(&self) -> Result<AllThemesOutput> {
+        if self.existing.is_some() && self.target_dir.exists() {
+            return self.generate_into(&self.target_dir).await;
+        }
+
+        let parent = match self.target_dir.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => env::current_dir()?,
+        };
+        fs::create_dir_all(&parent)?;
+        let staging = tempfile::Builder::new()
+            .prefix(".portfolio-arcade-")
+            .tempdir_in(&parent)
+            .with_context(|| {
+                format!(
+                    "failed to create a staging directory in {}",
+                    parent.display()
+                )
+            })?;
+
+        let output = self.generate_into(staging.path()).await?;
+
+        if self.target_dir.exists() {
+            return Err(ArcadeError::TargetExists(self.target_dir.clone()).into());
+        }
+        fs::rename(staging.path(), &self.target_dir).with_context(|| {
+            format!(
+                "failed to move the portfolios into {}",
+                self.target_dir.display()
+            )
+        })?;
+
+        // report where the portfolios ended up, not where they were staged
+        Ok(AllThemesOutput {
+            themes: output
+                .themes
+                .into_iter()
+                .map(|theme| ThemeOutput {
+                    path: self.target_dir.join(&theme.theme.id),
+                    ..theme
+                })
+                .collect(),
+            landing_page: output.landing_page,
+        })
+    }
+
+    async fn generate_into(&self, root: &Path) -> Result<AllThemesOutput> {
+        let mut outputs = Vec::new();
+        for theme in Theme::available_themes() {
+            let mut config = self.config.clone();
+            config.theme = theme.id.clone();
+
+            let mut generator = TemplateGenerator::with_existing_policy(
+                format!("{}-{}", self.project_name, theme.id),
+                config,
+                root.join(&theme.id),
+                self.existing,
+            )?;
+            generator.assets_dir = self.assets_dir.clone();
+            let report = generator.generate().await?;
+            outputs.push(ThemeOutput {
+                theme,
+                path: generator.target_dir,
+                report,
+            });
+        }
+
+        let themes: Vec<Theme> = outputs.iter().map(|o| o.theme.clone()).collect();
+        let mut plan = GenerationPlan::default();
+        plan.write(LANDING_PAGE, landing_page(&self.config, &themes));
+        let landing_page = match self.existing {
+            Some(policy) => plan.apply_to(root, policy)?,
+            None => {
+                plan.write_to(root)?;
+                plan.created_report()
+            }
+        };
+
+        Ok(AllThemesOutput {
+            themes: outputs,
+            landing_page,
+        })
+    }
+}
+
+// a dependency-free page linking to each theme's directory
+pub fn landing_page(config: &PortfolioConfig, themes: &[Theme]) -> String {
+    let name = escape_html(&config.user.name);
+    let title = escape_html(&config.user.title);
+
+    let links: String = themes
+        .iter()
+        .map(|theme| {
+            format!(
+                r#"      <a class="console" href="./{id}/">
+        <strong>{name}</strong>
+        <span>{description}</span>
+      </a>
+"#,
+                id = theme.id,
+                name = escape_html(&theme.display_name),
+                description = escape_html(&theme.description),
+            )
+        })
+        .collect();
+
+    format!(
+        r#"<!doctype html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1">
+    <title>{name} - {title}</title>
+    <style>
+      body {{ margin: 0; min-height: 100vh; display: flex; flex-direction: column; align-items: center; justify-content: center; font-family: system-ui, sans-serif; background: #0b0d17; color: #f5f5f7; }}
+      h1 {{ margin: 0; font-size: 2rem; }}
+      p {{ margin: 0.5rem 0 2rem; color: #a1a1aa; }}
+      nav {{ display: flex; flex-wrap: wrap; gap: 1rem; justify-content: center; padding: 0 1rem; }}
+      .console {{ display: flex; flex-direction: column; gap: 0.5rem; width: 14rem; padding: 1.5rem; border-radius: 1rem; background: #1c1f2e; color: inherit; text-decoration: none; transition: transform 0.15s; }}
+      .console:hover, .console:focus {{ transform: translateY(-4px); outline: 2px solid #5b8cff; }}
+      .console span {{ color: #a1a1aa; font-size: 0.9rem; }}
+    </style>
+  </head>
+  <body>
+    <h1>{name}</h1>
+    <p>{title} - pick a console</p>
+    <nav>
+{links}    </nav>
+  </body>
+</html>
+"#
+    )
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -2,6 +2,7 @@
 // handles npm install and starting the development server
 #![allow(dead_code)]
 
+use crate::output::Output;
 use anyhow::{anyhow, Result};
 use colored::*;
 use std::path::Path;
@@ -53,8 +54,20 @@ impl PackageManager {
             PackageManager::Yarn => vec!["dev"],
         }
     }
+
+    // returns the production build command args
+    pub fn build_args(&self) -> Vec<&str> {
+        match self {
+            PackageManager::Npm => vec!["run", "build"],
+            PackageManager::Pnpm => vec!["build"],
+            PackageManager::Yarn => vec!["build"],
+        }
+    }
 }
 
+// env var the template next.config.mjs reads to switch on static export
+pub const STATIC_EXPORT_ENV: &str = "PORTFOLIO_ARCADE_EXPORT";
+
 pub struct DependencyManager {
     pub project_path: std::path::PathBuf,
     pub package_manager: PackageManager,
@@ -70,11 +83,17 @@ impl DependencyManager {
     }
 
     // installs dependencies for the project
-    pub async fn install_dependencies(&self) -> Result<()> {
+    pub async fn install_dependencies(&self, out: &Output) -> Result<()> {
         // check if the package manager is available
         self.check_package_manager_available().await?;
 
-        println!("{}", "installing dependencies...".cyan());
+        out.line(
+            format!(
+                "installing dependencies with {}...",
+                self.package_manager.command()
+            )
+            .cyan(),
+        );
 
         let mut cmd = Command::new(self.package_manager.command());
         cmd.args(self.package_manager.install_args())
@@ -89,7 +108,38 @@ impl DependencyManager {
             return Err(anyhow!("failed to install dependencies: {}", stderr));
         }
 
-        println!("{}", "dependencies installed successfully".green());
+        out.line("dependencies installed successfully".green());
+        Ok(())
+    }
+
+    // whether dependencies still have to be installed
+    pub fn needs_install(&self) -> bool {
+        !self.project_path.join("node_modules").is_dir()
+    }
+
+    // runs the production build with static export switched on
+    // the exported site ends up in out/
+    pub async fn build_static_export(&self) -> Result<()> {
+        self.check_package_manager_available().await?;
+
+        let mut cmd = Command::new(self.package_manager.command());
+        cmd.args(self.package_manager.build_args())
+            .current_dir(&self.project_path)
+            .env(STATIC_EXPORT_ENV, "1")
+            .env("NEXT_TELEMETRY_DISABLED", "1")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let output = cmd.output().await?;
+
+        if !output.status.success() {
+            // next.js reports compile errors on stdout
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let log = format!("{}\n{}", stdout.trim(), stderr.trim());
+            return Err(anyhow!("production build failed:\n{}", log.trim()));
+        }
+
         Ok(())
     }
 
@@ -134,7 +184,7 @@ impl DependencyManager {
     }
 
     // checks if the detected package manager is available on the system
-    async fn check_package_manager_available(&self) -> Result<()> {
+    pub async fn check_package_manager_available(&self) -> Result<()> {
         let mut cmd = Command::new(self.package_manager.command());
         cmd.arg("--version")
             .stdout(Stdio::null())
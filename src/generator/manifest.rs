@@ -0,0 +1,128 @@
+// the record of how a project was generated, written next to its files
+// keeps the source config the theme adapter can't give back, and hashes to spot edited files
+
+use crate::generator::plan::{FileSource, GenerationPlan, GenerationReport};
+use crate::generator::upgrade::{path_key, sha256};
+use crate::generator::GeneratedProject;
+use crate::models::{CmsType, PortfolioConfig};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const MANIFEST_FILE: &str = ".portfolio-arcade.json";
+
+const MANIFEST_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Manifest {
+    pub format_version: u32,
+    // the portfolio-arcade version that generated the project
+    pub tool_version: String,
+    pub theme: String,
+    // one hash over every file copied from the template
+    pub template_hash: String,
+    pub cms: CmsType,
+    // the config as given, before the theme adapter reshaped it
+    pub config: PortfolioConfig,
+    // sha256 of every generated file, keyed by its path relative to the project
+    pub files: BTreeMap<String, String>,
+}
+
+impl Manifest {
+    pub fn new(config: &PortfolioConfig, plan: &GenerationPlan) -> Result<Self> {
+        let mut files = BTreeMap::new();
+        let mut template = BTreeMap::new();
+        for file in &plan.files {
+            let key = path_key(&file.path);
+            let contents = file.contents()?;
+            files.insert(key.clone(), sha256(&contents));
+            if let FileSource::Template(_) = file.source {
+                template.insert(key, contents);
+            }
+        }
+
+        // path and content both count, so renaming a template file changes the hash
+        let mut hashed = Vec::new();
+        for (path, contents) in template {
+            hashed.extend_from_slice(path.as_bytes());
+            hashed.push(0);
+            hashed.extend_from_slice(&contents);
+            hashed.push(0);
+        }
+
+        Ok(Self {
+            format_version: MANIFEST_VERSION,
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            theme: config.theme.clone(),
+            template_hash: sha256(&hashed),
+            cms: config.cms.clone(),
+            config: config.clone(),
+            files,
+        })
+    }
+
+    // none for projects generated before the manifest existed
+    pub fn load(root: &Path) -> Result<Option<Self>> {
+        let path = root.join(MANIFEST_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let manifest = serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse {}", path.display()))?;
+        Ok(Some(manifest))
+    }
+
+    pub fn save(&self, root: &Path) -> Result<()> {
+        fs::write(
+            root.join(MANIFEST_FILE),
+            serde_json::to_string_pretty(self)?,
+        )?;
+        Ok(())
+    }
+
+    // after generating into an existing directory, makes the manifest describe what's on
+    // disk: files a policy skipped or merged are hashed as they are now, and when that
+    // includes portfolio.json the config is read back from it
+    // call before saving, so an older manifest can still vouch for the existing data
+    pub fn match_disk(&mut self, root: &Path, report: &GenerationReport) -> Result<()> {
+        let data_file = Path::new("public").join("data").join("portfolio.json");
+        let mut data_kept = false;
+        for path in report.skipped.iter().chain(&report.merged) {
+            let contents = fs::read(root.join(path))
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            self.record(path, &contents);
+            data_kept |= *path == data_file;
+        }
+
+        if data_kept {
+            self.config = GeneratedProject::open(root)?.load_config()?;
+        }
+        Ok(())
+    }
+
+    // records new contents the tool itself wrote, so they don't count as edits
+    pub fn record(&mut self, path: &Path, contents: &[u8]) {
+        self.files.insert(path_key(path), sha256(contents));
+    }
+
+    // whether a generated file was changed or deleted since generation
+    pub fn is_edited(&self, root: &Path, path: &Path) -> bool {
+        match (self.files.get(&path_key(path)), fs::read(root.join(path))) {
+            (Some(hash), Ok(contents)) => *hash != sha256(&contents),
+            _ => true,
+        }
+    }
+
+    // every generated file that no longer matches what was generated
+    pub fn edited_files(&self, root: &Path) -> Vec<PathBuf> {
+        self.files
+            .keys()
+            .map(PathBuf::from)
+            .filter(|path| self.is_edited(root, path))
+            .collect()
+    }
+}
@@ -1,7 +1,13 @@
 // template generation modules
 // handles copying templates and configuring them with user data
 
+pub mod all_themes;
 pub mod dependencies;
+pub mod manifest;
+pub mod plan;
+pub mod project;
 pub mod template;
+pub mod upgrade;
 
+pub use project::GeneratedProject;
 pub use template::TemplateGenerator;
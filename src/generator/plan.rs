@@ -0,0 +1,277 @@
+// the files a generation produces, worked out before anything touches disk
+// lets generation be previewed, staged or written with different policies
+
+use crate::utils::json;
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub enum FileSource {
+    // copied unchanged from the template
+    Template(PathBuf),
+    // generated content
+    Generated(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileAction {
+    Copy,
+    Write,
+}
+
+// what to do with files that already exist when generating into a directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExistingFiles {
+    // generated files replace existing ones
+    Overwrite,
+    // existing files are left alone
+    SkipExisting,
+    // json files are merged key by key, other existing files are left alone
+    Merge,
+}
+
+impl FromStr for ExistingFiles {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "overwrite" => Ok(ExistingFiles::Overwrite),
+            "skip-existing" | "skip" => Ok(ExistingFiles::SkipExisting),
+            "merge" => Ok(ExistingFiles::Merge),
+            _ => Err(format!(
+                "unknown policy '{}' (expected overwrite, skip-existing or merge)",
+                s
+            )),
+        }
+    }
+}
+
+// what happened, or would happen, to one planned file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Create,
+    Replace,
+    Merge,
+    Skip,
+    Unchanged,
+}
+
+#[derive(Debug, Clone)]
+pub struct Resolution {
+    pub path: PathBuf,
+    pub outcome: Outcome,
+    // bytes to write, none when the file is left as it is
+    pub contents: Option<Vec<u8>>,
+}
+
+// files grouped by outcome, relative to the target directory
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GenerationReport {
+    pub created: Vec<PathBuf>,
+    pub replaced: Vec<PathBuf>,
+    pub merged: Vec<PathBuf>,
+    pub skipped: Vec<PathBuf>,
+    pub unchanged: Vec<PathBuf>,
+}
+
+impl GenerationReport {
+    fn record(&mut self, path: PathBuf, outcome: Outcome) {
+        match outcome {
+            Outcome::Create => self.created.push(path),
+            Outcome::Replace => self.replaced.push(path),
+            Outcome::Merge => self.merged.push(path),
+            Outcome::Skip => self.skipped.push(path),
+            Outcome::Unchanged => self.unchanged.push(path),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PlannedFile {
+    // relative to the target directory
+    pub path: PathBuf,
+    pub source: FileSource,
+}
+
+impl PlannedFile {
+    pub fn action(&self) -> FileAction {
+        match self.source {
+            FileSource::Template(_) => FileAction::Copy,
+            FileSource::Generated(_) => FileAction::Write,
+        }
+    }
+
+    pub fn contents(&self) -> Result<Vec<u8>> {
+        match &self.source {
+            FileSource::Template(source) => {
+                fs::read(source).with_context(|| format!("failed to read {}", source.display()))
+            }
+            FileSource::Generated(content) => Ok(content.clone().into_bytes()),
+        }
+    }
+
+    // decides what the policy does with this file given what's already on disk
+    pub fn resolve(&self, root: &Path, policy: ExistingFiles) -> Result<Resolution> {
+        let contents = self.contents()?;
+        let resolution = |outcome, contents| Resolution {
+            path: self.path.clone(),
+            outcome,
+            contents,
+        };
+
+        let existing = match fs::read(root.join(&self.path)) {
+            Ok(existing) => existing,
+            Err(_) => return Ok(resolution(Outcome::Create, Some(contents))),
+        };
+        if existing == contents {
+            return Ok(resolution(Outcome::Unchanged, None));
+        }
+
+        Ok(match policy {
+            ExistingFiles::Overwrite => resolution(Outcome::Replace, Some(contents)),
+            ExistingFiles::SkipExisting => resolution(Outcome::Skip, None),
+            ExistingFiles::Merge => match merge_json_files(&existing, &contents) {
+                Some(merged) if merged == existing => resolution(Outcome::Unchanged, None),
+                Some(merged) => resolution(Outcome::Merge, Some(merged)),
+                None => resolution(Outcome::Skip, None),
+            },
+        })
+    }
+
+    // writes the file under a root directory
+    pub fn write_to(&self, root: &Path) -> Result<()> {
+        let target = root.join(&self.path);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        match &self.source {
+            FileSource::Template(source) => {
+                fs::copy(source, &target)
+                    .with_context(|| format!("failed to copy {}", source.display()))?;
+            }
+            FileSource::Generated(content) => {
+                fs::write(&target, content)
+                    .with_context(|| format!("failed to write {}", target.display()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GenerationPlan {
+    pub files: Vec<PlannedFile>,
+    // directories created even when nothing is written into them
+    pub dirs: Vec<PathBuf>,
+}
+
+impl GenerationPlan {
+    pub fn copy(&mut self, path: PathBuf, source: PathBuf) {
+        self.set(path, FileSource::Template(source));
+    }
+
+    // generated content replaces any template copy of the same file
+    pub fn write(&mut self, path: impl Into<PathBuf>, content: impl Into<String>) {
+        self.set(path.into(), FileSource::Generated(content.into()));
+    }
+
+    pub fn dir(&mut self, path: impl Into<PathBuf>) {
+        let path = path.into();
+        if !self.dirs.contains(&path) {
+            self.dirs.push(path);
+        }
+    }
+
+    fn set(&mut self, path: PathBuf, source: FileSource) {
+        match self.files.iter_mut().find(|file| file.path == path) {
+            Some(file) => file.source = source,
+            None => self.files.push(PlannedFile { path, source }),
+        }
+    }
+
+    pub fn get(&self, path: &Path) -> Option<&PlannedFile> {
+        self.files.iter().find(|file| file.path == path)
+    }
+
+    // generated content for a file, if the plan writes it
+    pub fn generated(&self, path: &Path) -> Option<&str> {
+        match &self.get(path)?.source {
+            FileSource::Generated(content) => Some(content),
+            FileSource::Template(_) => None,
+        }
+    }
+
+    // works out the outcome for every file without writing anything
+    pub fn resolve(&self, root: &Path, policy: ExistingFiles) -> Result<Vec<Resolution>> {
+        self.files
+            .iter()
+            .map(|file| file.resolve(root, policy))
+            .collect()
+    }
+
+    // writes into a directory that already exists, following the policy
+    // every outcome is worked out before the first write
+    pub fn apply_to(&self, root: &Path, policy: ExistingFiles) -> Result<GenerationReport> {
+        let resolutions = self.resolve(root, policy)?;
+
+        for dir in &self.dirs {
+            fs::create_dir_all(root.join(dir))?;
+        }
+
+        let mut report = GenerationReport::default();
+        for resolution in resolutions {
+            if let Some(contents) = &resolution.contents {
+                let target = root.join(&resolution.path);
+                if let Some(parent) = target.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&target, contents)
+                    .with_context(|| format!("failed to write {}", target.display()))?;
+            }
+            report.record(resolution.path, resolution.outcome);
+        }
+
+        Ok(report)
+    }
+
+    // a report for a fresh generation, where every file is created
+    pub fn created_report(&self) -> GenerationReport {
+        GenerationReport {
+            created: self.files.iter().map(|file| file.path.clone()).collect(),
+            ..Default::default()
+        }
+    }
+
+    // writes every file and directory under root
+    pub fn write_to(&self, root: &Path) -> Result<()> {
+        fs::create_dir_all(root)?;
+        for dir in &self.dirs {
+            fs::create_dir_all(root.join(dir))?;
+        }
+        for file in &self.files {
+            file.write_to(root)?;
+        }
+        Ok(())
+    }
+}
+
+// generated json layered over the existing file, none when either isn't a json object
+fn merge_json_files(existing: &[u8], generated: &[u8]) -> Option<Vec<u8>> {
+    let mut existing: Value = serde_json::from_slice(existing).ok()?;
+    let generated: Value = serde_json::from_slice(generated).ok()?;
+    if !existing.is_object() || !generated.is_object() {
+        return None;
+    }
+
+    json::merge(&mut existing, generated);
+    serde_json::to_string_pretty(&existing)
+        .ok()
+        .map(String::into_bytes)
+}
@@ -0,0 +1,167 @@
+// access to a portfolio project that was already generated
+// reads public/data/portfolio.json back into the canonical config and writes it again
+
+use crate::error::ArcadeError;
+use crate::generator::manifest::Manifest;
+use crate::models::{PortfolioConfig, Post, Theme, POSTS_DIR};
+use anyhow::{anyhow, Context, Result};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub struct GeneratedProject {
+    pub root: PathBuf,
+    pub theme: Theme,
+    pub data: Value,
+}
+
+impl GeneratedProject {
+    // opens a generated project by reading its portfolio data file
+    pub fn open(root: &Path) -> Result<Self> {
+        let data_path = Self::data_path_for(root);
+        if !data_path.exists() {
+            return Err(ArcadeError::NotAPortfolio(format!(
+                "'{}' doesn't look like a generated portfolio (missing public/data/portfolio.json)",
+                root.display()
+            ))
+            .into());
+        }
+
+        let content = fs::read_to_string(&data_path)
+            .with_context(|| format!("failed to read {}", data_path.display()))?;
+        let data: Value = serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse {}", data_path.display()))?;
+
+        let theme_id = data["theme"]
+            .as_str()
+            .ok_or_else(|| anyhow!("{} has no theme field", data_path.display()))?;
+        let theme =
+            Theme::find_by_id(theme_id).ok_or_else(|| anyhow!("theme '{}' not found", theme_id))?;
+
+        Ok(Self {
+            root: root.to_path_buf(),
+            theme,
+            data,
+        })
+    }
+
+    pub fn data_path(&self) -> PathBuf {
+        Self::data_path_for(&self.root)
+    }
+
+    fn data_path_for(root: &Path) -> PathBuf {
+        root.join(Self::data_file())
+    }
+
+    // the data file relative to the project root
+    fn data_file() -> PathBuf {
+        Path::new("public").join("data").join("portfolio.json")
+    }
+
+    // the project name recorded in package.json, falling back to the directory name
+    pub fn name(&self) -> String {
+        fs::read_to_string(self.root.join("package.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+            .and_then(|package| package["name"].as_str().map(|s| s.to_string()))
+            .or_else(|| self.dir_name())
+            .unwrap_or_else(|| "my-portfolio".to_string())
+    }
+
+    pub fn dir_name(&self) -> Option<String> {
+        self.root
+            .canonicalize()
+            .ok()
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+    }
+
+    // the config the project was generated from, with the posts currently in content/posts/
+    pub fn load_config(&self) -> Result<PortfolioConfig> {
+        let mut config = self.stored_config()?;
+        let posts_dir = self.root.join(POSTS_DIR);
+        if posts_dir.is_dir() {
+            config.posts = Post::load_dir(&posts_dir)?;
+        }
+        Ok(config)
+    }
+
+    // whether portfolio.json is behind the posts in a loaded config
+    pub fn posts_changed(&self, config: &PortfolioConfig) -> bool {
+        let current = self
+            .data
+            .get("posts")
+            .cloned()
+            .unwrap_or(Value::Array(Vec::new()));
+        current != self.theme.adapt_posts(&config.posts)
+    }
+
+    // the manifest's config as long as portfolio.json wasn't edited since
+    // otherwise it's rebuilt from the theme-adapted data file
+    fn stored_config(&self) -> Result<PortfolioConfig> {
+        if let Some(manifest) = Manifest::load(&self.root)? {
+            if manifest.config.theme == self.theme.id
+                && !manifest.is_edited(&self.root, &Self::data_file())
+            {
+                return Ok(manifest.config);
+            }
+        }
+
+        let defaults = PortfolioConfig::default();
+
+        let user = match self.data.get("user") {
+            Some(user) => serde_json::from_value(user.clone())?,
+            None => defaults.user,
+        };
+        let projects = match self.data.get("projects") {
+            Some(projects) => self.theme.recover_projects(projects)?,
+            None => Vec::new(),
+        };
+        let skills = self.theme.recover_skills(&self.data["skills"]);
+        let experience = self.theme.recover_timeline(&self.data["experience"]);
+        let education = self.theme.recover_timeline(&self.data["education"]);
+        let posts = self.theme.recover_posts(&self.data["posts"]);
+        let cms = match self.data["cms"].as_str() {
+            Some(cms) => cms.parse().map_err(|e: String| anyhow!(e))?,
+            None => defaults.cms,
+        };
+        let dev_port = self.data["dev_port"]
+            .as_u64()
+            .and_then(|port| u16::try_from(port).ok())
+            .unwrap_or(defaults.dev_port);
+
+        Ok(PortfolioConfig {
+            user,
+            projects,
+            skills,
+            experience,
+            education,
+            posts,
+            theme: self.theme.id.clone(),
+            cms,
+            dev_port,
+        })
+    }
+
+    // writes the config back through the theme adapter
+    // top-level keys added by hand to portfolio.json are kept
+    // the manifest follows so the new data doesn't count as a hand edit
+    pub fn save_config(&mut self, config: &PortfolioConfig) -> Result<()> {
+        let generated = self.theme.portfolio_data(config)?;
+
+        match (&mut self.data, generated) {
+            (Value::Object(existing), Value::Object(generated)) => existing.extend(generated),
+            (data, generated) => *data = generated,
+        }
+
+        let content = serde_json::to_string_pretty(&self.data)?;
+        fs::write(self.data_path(), &content)?;
+
+        if let Some(mut manifest) = Manifest::load(&self.root)? {
+            manifest.config = config.clone();
+            manifest.cms = config.cms.clone();
+            manifest.record(&Self::data_file(), content.as_bytes());
+            manifest.save(&self.root)?;
+        }
+        Ok(())
+    }
+}
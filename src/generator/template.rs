@@ -1,10 +1,14 @@
 // template copying and configuration logic
 // copies console themes and adapts them with user data
 
-use crate::models::{PortfolioConfig, Theme};
-use anyhow::{anyhow, Result};
+use crate::error::ArcadeError;
+use crate::generator::manifest::Manifest;
+use crate::generator::plan::{ExistingFiles, GenerationPlan, GenerationReport};
+use crate::generator::upgrade::TemplateBase;
+use crate::models::{PortfolioConfig, SocialPlatform, Theme, POSTS_DIR};
+use anyhow::{Context, Result};
 use serde_json::Value;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::{env, fs};
 
 pub struct TemplateGenerator {
@@ -13,10 +17,71 @@ pub struct TemplateGenerator {
     pub source_dir: PathBuf,
     pub target_dir: PathBuf,
     pub theme: Theme,
+    // set when generating into a directory that may already exist
+    pub existing: Option<ExistingFiles>,
+    // files copied over public/ after the template, like a cartridge's images
+    pub assets_dir: Option<PathBuf>,
 }
 
 impl TemplateGenerator {
     pub fn new(project_name: String, config: PortfolioConfig) -> Result<Self> {
+        let target_dir = env::current_dir()?.join(&project_name);
+        Self::with_target_dir(project_name, config, target_dir)
+    }
+
+    // like new, but generates into an explicit directory instead of ./<project_name>
+    pub fn with_target_dir(
+        project_name: String,
+        config: PortfolioConfig,
+        target_dir: PathBuf,
+    ) -> Result<Self> {
+        Self::with_existing_policy(project_name, config, target_dir, None)
+    }
+
+    // like with_target_dir, but an existing target is written into following the policy
+    // instead of being refused
+    pub fn with_existing_policy(
+        project_name: String,
+        config: PortfolioConfig,
+        target_dir: PathBuf,
+        existing: Option<ExistingFiles>,
+    ) -> Result<Self> {
+        let templates_dir = Self::find_templates_dir()?;
+
+        // find the selected theme
+        let theme = Theme::find_by_id(&config.theme).ok_or_else(|| {
+            ArcadeError::InvalidConfig(format!("theme: theme '{}' not found", config.theme))
+        })?;
+
+        let source_dir = templates_dir.join(&theme.template_dir);
+
+        // validate that source template exists
+        if !source_dir.exists() {
+            return Err(ArcadeError::TemplateMissing(format!(
+                "template directory not found: {}",
+                source_dir.display()
+            ))
+            .into());
+        }
+
+        // check if target directory already exists
+        if target_dir.exists() && existing.is_none() {
+            return Err(ArcadeError::TargetExists(target_dir).into());
+        }
+
+        Ok(Self {
+            project_name,
+            config,
+            source_dir,
+            target_dir,
+            theme,
+            existing,
+            assets_dir: None,
+        })
+    }
+
+    // every location checked for the templates directory, in lookup order
+    pub fn template_search_paths() -> Result<Vec<PathBuf>> {
         // get the current directory where templates should be located
         let current_dir = env::current_dir()?;
 
@@ -55,13 +120,21 @@ impl TemplateGenerator {
                 .unwrap_or_else(|| PathBuf::from("")),
         ]);
 
+        Ok(possible_template_dirs)
+    }
+
+    // resolves the first existing templates directory
+    pub fn find_templates_dir() -> Result<PathBuf> {
+        let current_dir = env::current_dir()?;
+        let possible_template_dirs = Self::template_search_paths()?;
+
         // Clone the paths for error message before moving
         let paths_for_error = possible_template_dirs.clone();
 
-        let templates_dir = possible_template_dirs
+        possible_template_dirs
             .into_iter()
             .find(|dir| dir.exists() && dir.is_dir())
-            .ok_or_else(|| anyhow!(
+            .ok_or_else(|| ArcadeError::TemplateMissing(format!(
                 "templates directory not found. Tried:\n{}\nCurrent directory: {}\nExecutable location: {}",
                 paths_for_error.iter()
                     .map(|p| format!("- {}", p.display()))
@@ -69,73 +142,102 @@ impl TemplateGenerator {
                     .join("\n"),
                 current_dir.display(),
                 env::current_exe().map(|p| p.display().to_string()).unwrap_or_else(|_| "unknown".to_string())
-            ))?;
-
-        // find the selected theme
-        let theme = Theme::find_by_id(&config.theme)
-            .ok_or_else(|| anyhow!("theme '{}' not found", config.theme))?;
-
-        let source_dir = templates_dir.join(&theme.template_dir);
-        let target_dir = current_dir.join(&project_name);
+            )).into())
+    }
 
-        // validate that source template exists
-        if !source_dir.exists() {
-            return Err(anyhow!(
-                "template directory not found: {}",
-                source_dir.display()
-            ));
+    // every step runs against a staging directory next to the target,
+    // which is renamed into place only once everything succeeded
+    // a failure drops the staging directory and leaves nothing behind
+    // an existing target with a policy set is written into file by file instead
+    pub async fn generate(&self) -> Result<GenerationReport> {
+        let plan = self.plan()?;
+
+        // the pristine output is kept as the ancestor for later upgrades
+        // it stays the plan's output even for skipped files, so upgrades treat them as edits
+        let base = TemplateBase::from_plan(&plan)?;
+        let mut manifest = Manifest::new(&self.config, &plan)?;
+
+        if let Some(policy) = self.existing {
+            if self.target_dir.exists() {
+                let report = plan.apply_to(&self.target_dir, policy)?;
+                manifest.match_disk(&self.target_dir, &report)?;
+                base.save(&self.target_dir)?;
+                manifest.save(&self.target_dir)?;
+                return Ok(report);
+            }
         }
 
-        // check if target directory already exists
-        if target_dir.exists() {
-            return Err(anyhow!(
-                "directory '{}' already exists",
-                target_dir.display()
-            ));
+        let parent = match self.target_dir.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => env::current_dir()?,
+        };
+        fs::create_dir_all(&parent)?;
+
+        // same parent, same filesystem, so the final rename is atomic
+        let staging = tempfile::Builder::new()
+            .prefix(".portfolio-arcade-")
+            .tempdir_in(&parent)
+            .with_context(|| {
+                format!(
+                    "failed to create a staging directory in {}",
+                    parent.display()
+                )
+            })?;
+        plan.write_to(staging.path())?;
+        base.save(staging.path())?;
+        manifest.save(staging.path())?;
+
+        // something may have claimed the target while we were writing
+        if self.target_dir.exists() {
+            return Err(ArcadeError::TargetExists(self.target_dir.clone()).into());
         }
-
-        Ok(Self {
-            project_name,
-            config,
-            source_dir,
-            target_dir,
-            theme,
-        })
+        fs::rename(staging.path(), &self.target_dir).with_context(|| {
+            format!(
+                "failed to move the portfolio into {}",
+                self.target_dir.display()
+            )
+        })?;
+
+        Ok(plan.created_report())
     }
 
-    pub async fn generate(&self) -> Result<()> {
+    // works out every file the generation produces without writing anything
+    pub fn plan(&self) -> Result<GenerationPlan> {
+        let mut plan = GenerationPlan::default();
+
         // step 1: copy template directory
-        self.copy_template()?;
+        self.copy_template(&mut plan)?;
 
         // step 2: update package.json
-        self.update_package_json()?;
+        self.update_package_json(&mut plan)?;
 
         // step 3: update layout.tsx with user info
-        self.update_layout_file()?;
+        self.update_layout_file(&mut plan)?;
 
         // step 4: create portfolio data file
-        self.create_portfolio_data()?;
+        self.create_portfolio_data(&mut plan)?;
 
         // step 5: update readme
-        self.update_readme()?;
+        self.update_readme(&mut plan)?;
 
         // step 6: setup cms if selected
-        self.setup_cms()?;
+        self.setup_cms(&mut plan)?;
 
-        Ok(())
+        // step 7: bring in the user's own assets
+        self.copy_assets(&mut plan)?;
+
+        Ok(plan)
     }
 
-    fn copy_template(&self) -> Result<()> {
+    fn copy_template(&self, plan: &mut GenerationPlan) -> Result<()> {
         use walkdir::WalkDir;
 
-        // Create target directory
-        fs::create_dir_all(&self.target_dir)?;
-
         // Directories and files to exclude
         let exclude_names = ["node_modules", ".next", "package-lock.json"];
 
         // Walk through source directory and copy files selectively
         for entry in WalkDir::new(&self.source_dir)
+            .sort_by_file_name()
             .into_iter()
             .filter_entry(|e| {
                 // Exclude specific directories and files
@@ -152,26 +254,40 @@ impl TemplateGenerator {
             }
 
             // Calculate relative path from source
-            let relative_path = path.strip_prefix(&self.source_dir)?;
-            let target_path = self.target_dir.join(relative_path);
+            let relative_path = path.strip_prefix(&self.source_dir)?.to_path_buf();
 
             if entry.file_type().is_dir() {
-                fs::create_dir_all(&target_path)?;
+                plan.dir(relative_path);
             } else {
-                // Ensure parent directory exists
-                if let Some(parent) = target_path.parent() {
-                    fs::create_dir_all(parent)?;
-                }
-                fs::copy(path, &target_path)?;
+                plan.copy(relative_path, path.to_path_buf());
             }
         }
 
         Ok(())
     }
 
-    fn update_package_json(&self) -> Result<()> {
-        let package_json_path = self.target_dir.join("package.json");
-        let content = fs::read_to_string(&package_json_path)?;
+    fn copy_assets(&self, plan: &mut GenerationPlan) -> Result<()> {
+        let assets_dir = match &self.assets_dir {
+            Some(dir) => dir,
+            None => return Ok(()),
+        };
+
+        for entry in walkdir::WalkDir::new(assets_dir).sort_by_file_name() {
+            let entry = entry?;
+            if entry.file_type().is_file() {
+                let relative = entry.path().strip_prefix(assets_dir)?;
+                plan.copy(
+                    Path::new("public").join(relative),
+                    entry.path().to_path_buf(),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn update_package_json(&self, plan: &mut GenerationPlan) -> Result<()> {
+        let content = fs::read_to_string(self.source_dir.join("package.json"))?;
 
         let mut package_json: Value = serde_json::from_str(&content)?;
 
@@ -180,56 +296,51 @@ impl TemplateGenerator {
             obj.insert("name".to_string(), Value::String(self.project_name.clone()));
         }
 
-        // write it back
-        let updated_content = serde_json::to_string_pretty(&package_json)?;
-        fs::write(package_json_path, updated_content)?;
+        plan.write("package.json", serde_json::to_string_pretty(&package_json)?);
 
         Ok(())
     }
 
-    fn update_layout_file(&self) -> Result<()> {
+    fn update_layout_file(&self, plan: &mut GenerationPlan) -> Result<()> {
         // find the layout file - it's in app/layout.tsx for next.js apps
-        let layout_path = self.target_dir.join("app").join("layout.tsx");
+        let layout_path = self.source_dir.join("app").join("layout.tsx");
 
         if layout_path.exists() {
-            self.theme.update_layout(&layout_path, &self.config)?;
+            let content = fs::read_to_string(&layout_path)?;
+            plan.write(
+                Path::new("app").join("layout.tsx"),
+                self.theme.layout_content(content, &self.config),
+            );
         }
 
         Ok(())
     }
 
-    fn create_portfolio_data(&self) -> Result<()> {
-        // create a data directory in public for client-side access
-        let public_data_dir = self.target_dir.join("public").join("data");
-        fs::create_dir_all(&public_data_dir)?;
-
-        // create portfolio.json with adapted project data
-        let portfolio_data = self.theme.adapt_projects(&self.config.projects)?;
-
-        let portfolio_file = public_data_dir.join("portfolio.json");
-        let full_config = serde_json::json!({
-            "user": self.config.user,
-            "projects": portfolio_data,
-            "theme": self.config.theme,
-            "cms": format!("{:?}", self.config.cms).to_lowercase(),
-            "dev_port": self.config.dev_port
-        });
-
-        let content = serde_json::to_string_pretty(&full_config)?;
-        fs::write(portfolio_file, content)?;
+    fn create_portfolio_data(&self, plan: &mut GenerationPlan) -> Result<()> {
+        // create portfolio.json in public for client-side access, with adapted project data
+        let full_config = self.theme.portfolio_data(&self.config)?;
+        plan.write(
+            Path::new("public").join("data").join("portfolio.json"),
+            serde_json::to_string_pretty(&full_config)?,
+        );
 
         // create a sample project directory structure
-        self.create_sample_content()?;
+        self.create_sample_content(plan)?;
+
+        // posts are authored as markdown, portfolio.json carries a copy for the templates
+        plan.dir(POSTS_DIR);
+        for post in &self.config.posts {
+            plan.write(
+                Path::new(POSTS_DIR).join(post.file_name()),
+                post.to_markdown(),
+            );
+        }
 
         Ok(())
     }
 
-    fn create_sample_content(&self) -> Result<()> {
-        // create content/projects directory
-        let content_dir = self.target_dir.join("content").join("projects");
-        fs::create_dir_all(&content_dir)?;
-
-        // create a sample project markdown file
+    fn create_sample_content(&self, plan: &mut GenerationPlan) -> Result<()> {
+        // create a sample project markdown file in content/projects
         let sample_project = r#"---
 title: "sample project"
 description: "a great example project showcasing your skills"
@@ -264,19 +375,20 @@ replace this content with your own projects by editing files in the `content/pro
 "#
         .to_string();
 
-        let sample_file = content_dir.join("sample-project.md");
-        fs::write(sample_file, sample_project)?;
+        plan.write(
+            Path::new("content")
+                .join("projects")
+                .join("sample-project.md"),
+            sample_project,
+        );
 
         // create images directory
-        let images_dir = self.target_dir.join("public").join("projects");
-        fs::create_dir_all(images_dir)?;
+        plan.dir(Path::new("public").join("projects"));
 
         Ok(())
     }
 
-    fn update_readme(&self) -> Result<()> {
-        let readme_path = self.target_dir.join("README.md");
-
+    fn update_readme(&self, plan: &mut GenerationPlan) -> Result<()> {
         let readme_content = format!(
             r#"# {} Portfolio
 
@@ -322,6 +434,23 @@ date: "2024-01-15"
 write about your project here...
 ```
 
+### writing posts
+
+Write articles as markdown files in `content/posts/`. Posts published elsewhere can set `url` and leave the body out:
+
+```markdown
+---
+title: "your post title"
+date: "2024-02-01"
+summary: "one line about it"
+tags: ["rust", "cli"]
+---
+
+the article itself...
+```
+
+Run `portfolio-arcade sync` (or `portfolio-arcade build`) to copy new posts into `public/data/portfolio.json`.
+
 ### updating your info
 
 Edit the user information in `data/portfolio.json`:
@@ -379,7 +508,7 @@ made with portfolio arcade
             self.get_cms_instructions()
         );
 
-        fs::write(readme_path, readme_content)?;
+        plan.write("README.md", readme_content);
         Ok(())
     }
 
@@ -397,18 +526,17 @@ made with portfolio arcade
         }.to_string()
     }
 
-    fn setup_cms(&self) -> Result<()> {
+    fn setup_cms(&self, plan: &mut GenerationPlan) -> Result<()> {
         match self.config.cms {
-            crate::models::CmsType::Decap => self.setup_decap_cms(),
-            crate::models::CmsType::Payload => self.setup_payload_cms(),
+            crate::models::CmsType::Decap => self.setup_decap_cms(plan),
+            crate::models::CmsType::Payload => self.setup_payload_cms(plan),
             crate::models::CmsType::None => Ok(()),
         }
     }
 
-    fn setup_decap_cms(&self) -> Result<()> {
+    fn setup_decap_cms(&self, plan: &mut GenerationPlan) -> Result<()> {
         // create public/admin directory
-        let admin_dir = self.target_dir.join("public").join("admin");
-        fs::create_dir_all(&admin_dir)?;
+        let admin_dir = Path::new("public").join("admin");
 
         // create admin index.html
         let admin_html = r#"<!doctype html>
@@ -424,7 +552,7 @@ made with portfolio arcade
 </body>
 </html>"#;
 
-        fs::write(admin_dir.join("index.html"), admin_html)?;
+        plan.write(admin_dir.join("index.html"), admin_html);
 
         // create decap cms config
         let config_yml = r#"backend:
@@ -450,6 +578,19 @@ collections:
       - {label: "Date", name: "date", widget: "date"}
       - {label: "Body", name: "body", widget: "markdown"}
 
+  - name: "posts"
+    label: "Posts"
+    folder: "content/posts"
+    create: true
+    slug: "{{slug}}"
+    fields:
+      - {label: "Title", name: "title", widget: "string"}
+      - {label: "Date", name: "date", widget: "datetime", format: "YYYY-MM-DD", date_format: "YYYY-MM-DD", time_format: false}
+      - {label: "Summary", name: "summary", widget: "text", required: false}
+      - {label: "Published At", name: "url", widget: "string", required: false}
+      - {label: "Tags", name: "tags", widget: "list", required: false}
+      - {label: "Body", name: "body", widget: "markdown", required: false}
+
   - name: "settings"
     label: "Settings"
     files:
@@ -466,20 +607,21 @@ collections:
               - {label: "Bio", name: "bio", widget: "text"}
               - label: "Social Links"
                 name: "social"
-                widget: "object"
+                widget: "list"
+                summary: "{{fields.label}}"
                 fields:
-                  - {label: "GitHub", name: "github", widget: "string", required: false}
-                  - {label: "LinkedIn", name: "linkedin", widget: "string", required: false}
-                  - {label: "Email", name: "email", widget: "string", required: false}
+                  - {label: "Platform", name: "platform", widget: "select", options: [PLATFORM_OPTIONS]}
+                  - {label: "URL", name: "url", widget: "string", hint: "use mailto:you@example.com for email"}
+                  - {label: "Label", name: "label", widget: "string", required: false}
 "#
-        .to_string();
+        .replace("PLATFORM_OPTIONS", &platform_options());
 
-        fs::write(admin_dir.join("config.yml"), config_yml)?;
+        plan.write(admin_dir.join("config.yml"), config_yml);
 
         Ok(())
     }
 
-    fn setup_payload_cms(&self) -> Result<()> {
+    fn setup_payload_cms(&self, plan: &mut GenerationPlan) -> Result<()> {
         // create a payload config file template
         let payload_config = r#"// payload cms configuration
 // you'll need to set up a payload server separately
@@ -498,10 +640,30 @@ export const payloadConfig = {
         { name: 'content', type: 'richText' },
       ],
     },
+    {
+      slug: 'posts',
+      fields: [
+        { name: 'title', type: 'text', required: true },
+        { name: 'date', type: 'date' },
+        { name: 'summary', type: 'textarea' },
+        { name: 'url', type: 'text' },
+        { name: 'tags', type: 'array', of: [{ type: 'text' }] },
+        { name: 'body', type: 'richText' },
+      ],
+    },
   ],
 };"#;
 
-        fs::write(self.target_dir.join("payload.config.js"), payload_config)?;
+        plan.write("payload.config.js", payload_config);
         Ok(())
     }
 }
+
+// the platform ids as a yaml flow list, in the order the cms offers them
+fn platform_options() -> String {
+    SocialPlatform::ALL
+        .iter()
+        .map(|platform| format!("\"{}\"", platform.id()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,281 @@
+// moving a generated project onto the currently bundled template
+// three-way merges each file: the base it was generated from, the new template and the user's copy
+
+use crate::generator::plan::GenerationPlan;
+use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+// what every generated file looked like right after generation, the merge ancestor
+// gzipped, it holds a full copy of the template's text files
+pub const BASE_FILE: &str = ".portfolio-arcade-base.json.gz";
+
+// the uncompressed base older versions wrote, still read and replaced on the next save
+const LEGACY_BASE_FILE: &str = ".portfolio-arcade-base.json";
+
+const BASE_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateBase {
+    pub version: u32,
+    pub files: BTreeMap<String, BaseEntry>,
+}
+
+// text files keep their content for merging, anything else only a hash
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BaseEntry {
+    Text(String),
+    Sha256(String),
+}
+
+impl BaseEntry {
+    fn from_bytes(bytes: Vec<u8>) -> Self {
+        match String::from_utf8(bytes) {
+            Ok(text) => BaseEntry::Text(text),
+            Err(e) => BaseEntry::Sha256(sha256(e.as_bytes())),
+        }
+    }
+
+    fn matches(&self, bytes: &[u8]) -> bool {
+        match self {
+            BaseEntry::Text(text) => text.as_bytes() == bytes,
+            BaseEntry::Sha256(hash) => *hash == sha256(bytes),
+        }
+    }
+}
+
+impl TemplateBase {
+    pub fn from_plan(plan: &GenerationPlan) -> Result<Self> {
+        let mut files = BTreeMap::new();
+        for file in &plan.files {
+            files.insert(
+                path_key(&file.path),
+                BaseEntry::from_bytes(file.contents()?),
+            );
+        }
+        Ok(Self {
+            version: BASE_VERSION,
+            files,
+        })
+    }
+
+    // none for projects generated before upgrades were supported
+    pub fn load(root: &Path) -> Result<Option<Self>> {
+        let path = root.join(BASE_FILE);
+        let legacy = root.join(LEGACY_BASE_FILE);
+        let content = if path.exists() {
+            let mut content = String::new();
+            GzDecoder::new(
+                fs::File::open(&path)
+                    .with_context(|| format!("failed to read {}", path.display()))?,
+            )
+            .read_to_string(&mut content)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+            content
+        } else if legacy.exists() {
+            fs::read_to_string(&legacy)
+                .with_context(|| format!("failed to read {}", legacy.display()))?
+        } else {
+            return Ok(None);
+        };
+        let base = serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse {}", path.display()))?;
+        Ok(Some(base))
+    }
+
+    pub fn save(&self, root: &Path) -> Result<()> {
+        let path = root.join(BASE_FILE);
+        let file = fs::File::create(&path)
+            .with_context(|| format!("failed to create {}", path.display()))?;
+        let mut encoder = GzEncoder::new(file, Compression::best());
+        encoder.write_all(serde_json::to_string(self)?.as_bytes())?;
+        encoder.finish()?;
+
+        let legacy = root.join(LEGACY_BASE_FILE);
+        if legacy.exists() {
+            fs::remove_file(legacy)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpgradeOutcome {
+    // the template changed and the user hadn't touched the file
+    Updated,
+    // new in the template
+    Added,
+    // dropped from the template and untouched by the user
+    Removed,
+    // both sides changed and the changes merged cleanly
+    Merged,
+    // both sides changed the same lines, the file now has conflict markers
+    Conflict,
+    // both sides changed a file that can't be merged, the user's copy is kept
+    Kept,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpgradeStep {
+    pub path: PathBuf,
+    pub outcome: UpgradeOutcome,
+    // bytes to write, none to delete or keep the file
+    pub contents: Option<Vec<u8>>,
+}
+
+// files grouped by outcome
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpgradeReport {
+    pub updated: Vec<PathBuf>,
+    pub added: Vec<PathBuf>,
+    pub removed: Vec<PathBuf>,
+    pub merged: Vec<PathBuf>,
+    pub conflicts: Vec<PathBuf>,
+    pub kept: Vec<PathBuf>,
+}
+
+impl UpgradeReport {
+    pub fn from_steps(steps: &[UpgradeStep]) -> Self {
+        let mut report = Self::default();
+        for step in steps {
+            let list = match step.outcome {
+                UpgradeOutcome::Updated => &mut report.updated,
+                UpgradeOutcome::Added => &mut report.added,
+                UpgradeOutcome::Removed => &mut report.removed,
+                UpgradeOutcome::Merged => &mut report.merged,
+                UpgradeOutcome::Conflict => &mut report.conflicts,
+                UpgradeOutcome::Kept => &mut report.kept,
+            };
+            list.push(step.path.clone());
+        }
+        report
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.updated.is_empty()
+            && self.added.is_empty()
+            && self.removed.is_empty()
+            && self.merged.is_empty()
+            && self.conflicts.is_empty()
+            && self.kept.is_empty()
+    }
+}
+
+// works out what upgrading does to every file, writing nothing
+// files the template didn't change, or that already match it, produce no step
+pub fn plan_upgrade(
+    root: &Path,
+    base: &TemplateBase,
+    new_plan: &GenerationPlan,
+) -> Result<Vec<UpgradeStep>> {
+    let mut new_files = BTreeMap::new();
+    for file in &new_plan.files {
+        new_files.insert(path_key(&file.path), file.contents()?);
+    }
+
+    let paths: BTreeSet<&String> = base.files.keys().chain(new_files.keys()).collect();
+
+    let mut steps = Vec::new();
+    for key in paths {
+        let path = PathBuf::from(key);
+        let base = base.files.get(key);
+        let new = new_files.get(key);
+        let ours = fs::read(root.join(&path)).ok();
+
+        let template_changed = match (base, new) {
+            (Some(base), Some(new)) => !base.matches(new),
+            (None, None) => false,
+            _ => true,
+        };
+        if !template_changed || ours.as_ref() == new {
+            continue;
+        }
+
+        let user_changed = match (base, &ours) {
+            (Some(base), Some(ours)) => !base.matches(ours),
+            (None, None) => false,
+            _ => true,
+        };
+        let step = |outcome, contents| UpgradeStep {
+            path: path.clone(),
+            outcome,
+            contents,
+        };
+
+        if !user_changed {
+            steps.push(match (new, &ours) {
+                (Some(new), Some(_)) => step(UpgradeOutcome::Updated, Some(new.clone())),
+                (Some(new), None) => step(UpgradeOutcome::Added, Some(new.clone())),
+                (None, _) => step(UpgradeOutcome::Removed, None),
+            });
+            continue;
+        }
+
+        // a file both sides added merges against an empty ancestor
+        let ancestor = match base {
+            Some(BaseEntry::Text(text)) => Some(text.as_str()),
+            None => Some(""),
+            Some(BaseEntry::Sha256(_)) => None,
+        };
+        let ours_text = ours.as_ref().and_then(|o| std::str::from_utf8(o).ok());
+        let new_text = new.and_then(|n| std::str::from_utf8(n).ok());
+
+        steps.push(match (ancestor, ours_text, new_text) {
+            (Some(ancestor), Some(ours), Some(new)) => {
+                match diffy::MergeOptions::new()
+                    .set_conflict_style(diffy::ConflictStyle::Merge)
+                    .merge(ancestor, ours, new)
+                {
+                    Ok(merged) => step(UpgradeOutcome::Merged, Some(merged.into_bytes())),
+                    Err(conflicted) => {
+                        step(UpgradeOutcome::Conflict, Some(conflicted.into_bytes()))
+                    }
+                }
+            }
+            _ => step(UpgradeOutcome::Kept, None),
+        });
+    }
+
+    Ok(steps)
+}
+
+pub fn apply_upgrade(root: &Path, steps: &[UpgradeStep]) -> Result<()> {
+    for step in steps {
+        let target = root.join(&step.path);
+        match (&step.contents, step.outcome) {
+            (Some(contents), _) => {
+                if let Some(parent) = target.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&target, contents)
+                    .with_context(|| format!("failed to write {}", target.display()))?;
+            }
+            (None, UpgradeOutcome::Removed) => {
+                if target.exists() {
+                    fs::remove_file(&target)
+                        .with_context(|| format!("failed to remove {}", target.display()))?;
+                }
+            }
+            (None, _) => {}
+        }
+    }
+    Ok(())
+}
+
+// forward slashes so the base file reads the same on every platform
+pub(crate) fn path_key(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+pub fn sha256(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
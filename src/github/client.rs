@@ -8,6 +8,8 @@ use serde::de::DeserializeOwned;
 use std::env;
 
 use super::{GitHubLanguages, GitHubRepo};
+use crate::error::ArcadeError;
+use crate::models::UserDefaults;
 
 pub struct GitHubClient {
     client: reqwest::Client,
@@ -15,10 +17,31 @@ pub struct GitHubClient {
     token: Option<String>,
 }
 
+// GITHUB_TOKEN wins, then the token source from the user defaults file
+pub fn resolve_token() -> Option<String> {
+    resolve_token_with_source().map(|(token, _)| token)
+}
+
+// the token plus where it came from, e.g. "GITHUB_TOKEN" or "token_command `gh auth token`"
+pub fn resolve_token_with_source() -> Option<(String, String)> {
+    env::var("GITHUB_TOKEN")
+        .ok()
+        .filter(|token| !token.is_empty())
+        .map(|token| (token, "GITHUB_TOKEN".to_string()))
+        .or_else(|| {
+            UserDefaults::load()
+                .ok()
+                .and_then(|defaults| defaults.github.token_with_source())
+        })
+}
+
 impl GitHubClient {
     pub fn new() -> Result<Self> {
-        let token = env::var("GITHUB_TOKEN").ok();
+        Self::with_token(resolve_token())
+    }
 
+    // for callers that already resolved the token, so a token_command runs once
+    pub fn with_token(token: Option<String>) -> Result<Self> {
         let mut headers = HeaderMap::new();
         headers.insert(USER_AGENT, HeaderValue::from_static("portfolio-arcade"));
         headers.insert(
@@ -81,19 +104,37 @@ impl GitHubClient {
 
     /// Generic fetch method for JSON responses
     async fn fetch<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
-        let response = self.client.get(url).send().await?;
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| ArcadeError::GitHub(format!("GitHub API request failed: {}", e)))?;
 
         if !response.status().is_success() {
-            return Err(anyhow!(
+            return Err(ArcadeError::GitHub(format!(
                 "GitHub API request failed: {} - {}",
                 response.status(),
                 response.text().await?
-            ));
+            ))
+            .into());
         }
 
         Ok(response.json().await?)
     }
 
+    /// Fetch the login of the authenticated user, failing if the token is rejected
+    pub async fn authenticated_user(&self) -> Result<String> {
+        #[derive(serde::Deserialize)]
+        struct User {
+            login: String,
+        }
+
+        let url = format!("{}/user", self.base_url);
+        let user: User = self.fetch(&url).await?;
+        Ok(user.login)
+    }
+
     /// Check if authentication token is set
     pub fn is_authenticated(&self) -> bool {
         self.token.is_some()
@@ -2,11 +2,13 @@
 // Fetches project information, languages, and README content
 #![allow(dead_code)]
 
+use crate::models::Skill;
 use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 pub mod client;
+pub mod projects;
 
 pub use client::GitHubClient;
 
@@ -27,6 +29,27 @@ pub struct GitHubRepo {
     pub updated_at: String,
     pub pushed_at: String,
     pub default_branch: String,
+    #[serde(default)]
+    pub license: Option<GitHubLicense>,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitHubLicense {
+    pub key: String,
+    pub name: String,
+    pub spdx_id: Option<String>,
+}
+
+impl GitHubLicense {
+    // the spdx id, or the name for licenses github couldn't identify
+    pub fn label(&self) -> String {
+        match self.spdx_id.as_deref() {
+            Some(spdx_id) if !spdx_id.is_empty() && spdx_id != "NOASSERTION" => spdx_id.to_string(),
+            _ => self.name.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -78,14 +101,44 @@ pub fn parse_github_url(url: &str) -> Result<(String, String)> {
 
 // Convert language bytes to percentages and tech stack
 pub fn process_languages(languages: &GitHubLanguages) -> Vec<String> {
-    let total_bytes: u32 = languages.languages.values().sum();
+    let bytes: HashMap<String, u64> = languages
+        .languages
+        .iter()
+        .map(|(lang, bytes)| (lang.clone(), *bytes as u64))
+        .collect();
 
-    if total_bytes == 0 {
+    let significant = significant_languages(&bytes);
+    if significant.is_empty() {
         return vec!["Unknown".to_string()];
     }
+    significant.into_iter().map(|(lang, _)| lang).collect()
+}
+
+// Turn language byte counts (usually summed over several repos) into skills
+// Proficiency is each language's size relative to the most used one
+pub fn skills_from_languages(languages: &HashMap<String, u64>) -> Vec<Skill> {
+    let significant = significant_languages(languages);
+    let top = significant.first().map(|(_, pct)| *pct).unwrap_or(0.0);
+
+    significant
+        .into_iter()
+        .map(|(lang, pct)| Skill {
+            name: lang,
+            category: "language".to_string(),
+            proficiency: ((pct / top) * 100.0).round().clamp(1.0, 100.0) as u8,
+        })
+        .collect()
+}
+
+// Languages with >5% usage, or the top 3 if all are small, with their share, highest first
+fn significant_languages(languages: &HashMap<String, u64>) -> Vec<(String, f32)> {
+    let total_bytes: u64 = languages.values().sum();
+
+    if total_bytes == 0 {
+        return Vec::new();
+    }
 
     let mut lang_percentages: Vec<(String, f32)> = languages
-        .languages
         .iter()
         .map(|(lang, bytes)| {
             let percentage = (*bytes as f32 / total_bytes as f32) * 100.0;
@@ -93,23 +146,18 @@ pub fn process_languages(languages: &GitHubLanguages) -> Vec<String> {
         })
         .collect();
 
-    // Sort by percentage, highest first
-    lang_percentages.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+    // Sort by percentage, highest first (ties by name so the order is stable)
+    lang_percentages.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then(a.0.cmp(&b.0)));
 
-    // Return languages with >5% usage, or top 3 if all are small
-    let significant_langs: Vec<String> = lang_percentages
+    let significant_langs: Vec<(String, f32)> = lang_percentages
         .iter()
         .filter(|(_, percentage)| *percentage >= 5.0)
-        .map(|(lang, _)| lang.clone())
+        .cloned()
         .collect();
 
     if significant_langs.is_empty() {
         // If no significant languages, return top 3
-        lang_percentages
-            .into_iter()
-            .take(3)
-            .map(|(lang, _)| lang)
-            .collect()
+        lang_percentages.into_iter().take(3).collect()
     } else {
         significant_langs
     }
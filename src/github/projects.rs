@@ -0,0 +1,236 @@
+// turns github repository urls into portfolio projects
+// shared by the interactive tui and the headless cli commands
+
+use anyhow::Result;
+use std::collections::HashMap;
+
+use super::{
+    parse_github_url, process_languages, skills_from_languages, GitHubClient, GitHubLicense,
+};
+use crate::models::{Project, ProjectLinks, RepoMetadata, Skill};
+
+// projects built from github urls, plus a warning for each one that fell back to placeholder data
+#[derive(Debug, Default)]
+pub struct ImportedProjects {
+    pub projects: Vec<Project>,
+    // language byte counts summed over every fetched repo
+    pub languages: HashMap<String, u64>,
+    pub warnings: Vec<String>,
+}
+
+impl ImportedProjects {
+    // skills seeded from the languages the repos are written in
+    pub fn skills(&self) -> Vec<Skill> {
+        skills_from_languages(&self.languages)
+    }
+}
+
+// a single imported repo, see url_to_project
+#[derive(Debug)]
+pub struct ImportedProject {
+    pub project: Project,
+    // empty when github couldn't be reached
+    pub languages: HashMap<String, u64>,
+    pub warning: Option<String>,
+}
+
+impl ImportedProject {
+    pub fn skills(&self) -> Vec<Skill> {
+        skills_from_languages(&self.languages)
+    }
+}
+
+// fetches every url, falling back to placeholder data when github is unreachable
+pub async fn urls_to_projects(urls: &[String]) -> ImportedProjects {
+    let client = match GitHubClient::new() {
+        Ok(client) => client,
+        Err(e) => {
+            return ImportedProjects {
+                projects: urls
+                    .iter()
+                    .enumerate()
+                    .map(|(i, url)| placeholder_project(url, i))
+                    .collect(),
+                warnings: vec![format!(
+                    "failed to create GitHub client ({}), projects use placeholder data",
+                    e
+                )],
+                ..Default::default()
+            };
+        }
+    };
+
+    let mut imported = ImportedProjects::default();
+    for (i, url) in urls.iter().enumerate() {
+        let project = url_to_project(&client, url, i).await;
+        imported.projects.push(project.project);
+        for (language, bytes) in project.languages {
+            *imported.languages.entry(language).or_insert(0) += bytes;
+        }
+        imported.warnings.extend(project.warning);
+    }
+
+    imported
+}
+
+// fetches a single url, falling back to placeholder data when the fetch fails
+// the warning explains why a placeholder was used
+pub async fn url_to_project(client: &GitHubClient, url: &str, index: usize) -> ImportedProject {
+    match fetch_project_with_languages(client, url, index).await {
+        Ok((project, languages)) => ImportedProject {
+            project,
+            languages,
+            warning: None,
+        },
+        Err(e) => ImportedProject {
+            project: placeholder_project(url, index),
+            languages: HashMap::new(),
+            warning: Some(format!(
+                "failed to fetch data for {} ({}), using placeholder project",
+                url, e
+            )),
+        },
+    }
+}
+
+// picks the next index whose generated id isn't taken yet
+pub fn next_project_index(projects: &[Project]) -> usize {
+    let mut index = projects.len();
+    while projects
+        .iter()
+        .any(|p| p.id == format!("project-{}", index + 1))
+    {
+        index += 1;
+    }
+    index
+}
+
+// builds a project from live github repository data
+pub async fn fetch_project(client: &GitHubClient, url: &str, index: usize) -> Result<Project> {
+    Ok(fetch_project_with_languages(client, url, index).await?.0)
+}
+
+// like fetch_project, also returning the repo's language byte counts
+pub async fn fetch_project_with_languages(
+    client: &GitHubClient,
+    url: &str,
+    index: usize,
+) -> Result<(Project, HashMap<String, u64>)> {
+    // Parse GitHub URL
+    let (owner, repo) = parse_github_url(url)?;
+
+    // Fetch repository data
+    let repo_data = client.get_repo(&owner, &repo).await?;
+    let languages = client.get_languages(&owner, &repo).await?;
+
+    // Process languages into tech stack
+    let tech_stack = process_languages(&languages);
+
+    // Try to fetch README for full description
+    let full_description = client
+        .get_readme(&owner, &repo)
+        .await
+        .unwrap_or_else(|_| repo_data.description.clone().unwrap_or_default());
+
+    // Extract date from created_at
+    let date = repo_data
+        .created_at
+        .split('T')
+        .next()
+        .unwrap_or("2024-01-01");
+
+    let language_bytes = languages
+        .languages
+        .iter()
+        .map(|(language, bytes)| (language.clone(), *bytes as u64))
+        .collect();
+
+    let project = Project {
+        id: format!("project-{}", index + 1),
+        title: repo_data.name.replace(['-', '_'], " "),
+        description: repo_data.description.unwrap_or_else(|| {
+            format!(
+                "A {} project",
+                repo_data.language.as_deref().unwrap_or("software")
+            )
+        }),
+        full_description,
+        category: "Development".to_string(),
+        tech_stack,
+        featured: index == 0 || repo_data.stargazers_count > 10,
+        links: ProjectLinks {
+            github: Some(url.to_string()),
+            live: repo_data.homepage,
+            demo: None,
+        },
+        thumbnail: "".to_string(),
+        screenshots: vec![],
+        date: date.to_string(),
+        repo: Some(RepoMetadata {
+            stars: repo_data.stargazers_count,
+            forks: repo_data.forks_count,
+            topics: repo_data.topics,
+            language: repo_data.language,
+            pushed_at: Some(repo_data.pushed_at).filter(|pushed_at| !pushed_at.is_empty()),
+            license: repo_data.license.as_ref().map(GitHubLicense::label),
+            archived: repo_data.archived,
+        }),
+        extra: HashMap::new(),
+    };
+
+    Ok((project, language_bytes))
+}
+
+// builds a project from the url alone when github data is unavailable
+pub fn placeholder_project(url: &str, index: usize) -> Project {
+    let repo_name = url
+        .split('/')
+        .next_back()
+        .unwrap_or("project")
+        .replace(".git", "");
+
+    Project {
+        id: format!("project-{}", index + 1),
+        title: repo_name.replace('-', " "),
+        description: format!("Project hosted at {}", url),
+        full_description: "This project will be populated with GitHub data.".to_string(),
+        category: "Development".to_string(),
+        tech_stack: vec!["GitHub".to_string()],
+        featured: index == 0,
+        links: ProjectLinks {
+            github: Some(url.to_string()),
+            live: None,
+            demo: None,
+        },
+        thumbnail: "".to_string(),
+        screenshots: vec![],
+        date: "2024-01-01".to_string(),
+        repo: None,
+        extra: HashMap::new(),
+    }
+}
+
+// refreshes github-derived fields on an existing project in place
+// user-owned fields (id, title, category, featured, images) are left alone
+// returns the repo's language byte counts, none when it has no github link to refresh from
+pub async fn refresh_project(
+    client: &GitHubClient,
+    project: &mut Project,
+) -> Result<Option<HashMap<String, u64>>> {
+    let url = match &project.links.github {
+        Some(url) => url.clone(),
+        None => return Ok(None),
+    };
+
+    let (fresh, languages) = fetch_project_with_languages(client, &url, 0).await?;
+
+    project.description = fresh.description;
+    project.full_description = fresh.full_description;
+    project.tech_stack = fresh.tech_stack;
+    if fresh.links.live.is_some() {
+        project.links.live = fresh.links.live;
+    }
+    project.repo = fresh.repo;
+
+    Ok(Some(languages))
+}
@@ -2,9 +2,12 @@
 // exposes modules for integration testing
 
 pub mod cli;
+pub mod commands;
+pub mod error;
 pub mod generator;
 pub mod github;
 pub mod models;
+pub mod output;
 pub mod tui;
 pub mod update;
 pub mod utils;
@@ -3,51 +3,111 @@
 
 use anyhow::Result;
 use clap::Parser;
-use colored::*;
 
 mod cli;
+mod commands;
+mod error;
 mod generator;
 mod github;
 mod models;
+mod output;
 mod tui;
 mod update;
 mod utils;
 
 use cli::{Cli, Commands};
-use tui::App;
+use output::Output;
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() {
     let cli = Cli::parse();
+    let out = Output::new(cli.json);
 
+    // failures map onto stable exit codes so scripts can tell them apart
+    if let Err(err) = run(cli, out).await {
+        let code = error::exit_code_for(&err);
+        out.error(&err, code);
+        std::process::exit(code);
+    }
+}
+
+async fn run(cli: Cli, out: Output) -> Result<()> {
     // check for updates before showing welcome message
-    update::check_and_prompt_update().await?;
+    // the prompt waits on stdin, so headless and json runs skip it
+    if cli.command.is_interactive() && !out.is_json() {
+        update::check_and_prompt_update().await?;
+    }
 
     // show welcome message with some flair
-    println!();
-    println!(
-        "{}",
-        "╔══════════════════════════════════════════════╗".cyan()
-    );
-    println!(
-        "{}",
-        "║         portfolio arcade generator           ║".cyan()
-    );
-    println!(
-        "{}",
-        "╚══════════════════════════════════════════════╝".cyan()
-    );
-    println!();
+    out.banner();
 
     match cli.command {
-        Commands::Init { project_name } => {
-            // launch the interactive tui for project configuration
-            let app = App::new(project_name);
-            app.run().await?;
+        Commands::Init(args) => {
+            commands::init::run(args, &out).await?;
         }
         Commands::Update => {
             // check for updates manually
-            update::force_update_check().await?;
+            update::force_update_check(&out).await?;
+        }
+        Commands::Sync { path } => {
+            commands::sync::run(&path, &out).await?;
+        }
+        Commands::AddProject(args) => {
+            commands::projects::add(args, &out).await?;
+        }
+        Commands::RemoveProject { id, path } => {
+            commands::projects::remove(&path, &id, &out)?;
+        }
+        Commands::Batch(args) => {
+            commands::batch::run(args, &out).await?;
+        }
+        Commands::Pack {
+            path,
+            config,
+            output,
+        } => {
+            commands::cartridge::pack(&path, config.as_deref(), output, &out)?;
+        }
+        Commands::Unpack { cartridge, output } => {
+            commands::cartridge::unpack(&cartridge, output, &out)?;
+        }
+        Commands::SwitchTheme {
+            theme,
+            path,
+            output,
+        } => {
+            commands::switch_theme::run(&path, &theme, output, &out).await?;
+        }
+        Commands::Upgrade {
+            path,
+            dry_run,
+            record_base,
+        } => {
+            if record_base {
+                commands::upgrade::record_base(&path, &out)?;
+            } else {
+                commands::upgrade::run(&path, dry_run, &out).await?;
+            }
+        }
+        Commands::Build { path } => {
+            commands::build::run(&path, &out).await?;
+        }
+        Commands::Serve { path, port } => {
+            commands::serve::run(&path, port, &out).await?;
+        }
+        Commands::Deploy(args) => {
+            commands::deploy::run(args, &out).await?;
+        }
+        Commands::Doctor { path, port } => {
+            commands::doctor::run(&path, port, &out).await?;
+        }
+        Commands::Export {
+            format,
+            path,
+            config,
+            output,
+        } => {
+            commands::export::run(format, &path, config.as_deref(), &output, &out)?;
         }
     }
 
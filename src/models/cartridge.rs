@@ -0,0 +1,199 @@
+// .arcade cartridges - a portfolio definition packed into one file for sharing
+// a gzipped tarball holding cartridge.json (config, theme, versions) and assets/ with local images
+
+use crate::error::ArcadeError;
+use crate::models::portfolio::PortfolioConfig;
+use anyhow::{anyhow, Context, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+pub const CARTRIDGE_EXTENSION: &str = "arcade";
+
+// bumped whenever older versions of the tool couldn't read a cartridge
+pub const CARTRIDGE_VERSION: u32 = 1;
+
+const HEADER_FILE: &str = "cartridge.json";
+const ASSETS_DIR: &str = "assets";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cartridge {
+    pub format_version: u32,
+    // the portfolio-arcade version that packed it
+    pub tool_version: String,
+    pub theme: String,
+    pub config: PortfolioConfig,
+    // local assets keyed by the path the config uses, without the leading slash
+    #[serde(skip)]
+    pub assets: BTreeMap<String, Vec<u8>>,
+}
+
+impl Cartridge {
+    pub fn new(config: PortfolioConfig) -> Self {
+        Self {
+            format_version: CARTRIDGE_VERSION,
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            theme: config.theme.clone(),
+            config,
+            assets: BTreeMap::new(),
+        }
+    }
+
+    // avatar, thumbnails and screenshots that point at files rather than urls
+    pub fn local_assets(config: &PortfolioConfig) -> Vec<String> {
+        let mut paths = vec![config.user.avatar.as_str()];
+        for project in &config.projects {
+            paths.push(project.thumbnail.as_str());
+            paths.extend(project.screenshots.iter().map(String::as_str));
+        }
+
+        let mut assets: Vec<String> = paths
+            .into_iter()
+            .filter(|path| is_local(path))
+            .map(|path| path.trim_start_matches('/').to_string())
+            .collect();
+        assets.sort();
+        assets.dedup();
+        assets
+    }
+
+    // reads every local asset from a public directory
+    // returns the ones that weren't found, they stay referenced but unpacked
+    pub fn collect_assets(&mut self, public_dir: &Path) -> Vec<String> {
+        let mut missing = Vec::new();
+        for asset in Self::local_assets(&self.config) {
+            match safe_relative(&asset).and_then(|path| fs::read(public_dir.join(path)).ok()) {
+                Some(bytes) => {
+                    self.assets.insert(asset, bytes);
+                }
+                None => missing.push(asset),
+            }
+        }
+        missing
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let file = fs::File::create(path)
+            .with_context(|| format!("failed to create {}", path.display()))?;
+        let mut archive = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+
+        append(
+            &mut archive,
+            HEADER_FILE,
+            serde_json::to_string_pretty(self)?.as_bytes(),
+        )?;
+        for (asset, bytes) in &self.assets {
+            append(&mut archive, &format!("{}/{}", ASSETS_DIR, asset), bytes)?;
+        }
+
+        archive.into_inner()?.finish()?;
+        Ok(())
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        let file =
+            fs::File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+        Self::read_archive(file)
+            .with_context(|| format!("failed to read cartridge {}", path.display()))
+    }
+
+    fn read_archive(file: fs::File) -> Result<Self> {
+        let mut header: Option<Cartridge> = None;
+        let mut assets = BTreeMap::new();
+
+        let mut archive = tar::Archive::new(GzDecoder::new(file));
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            let path = entry.path()?.to_string_lossy().replace('\\', "/");
+            let mut bytes = Vec::new();
+            entry.read_to_end(&mut bytes)?;
+
+            if path == HEADER_FILE {
+                header = Some(serde_json::from_slice(&bytes)?);
+            } else if let Some(asset) = path.strip_prefix(&format!("{}/", ASSETS_DIR)) {
+                // never let an archive write outside the directory it's unpacked into
+                if safe_relative(asset).is_none() {
+                    return Err(anyhow!("asset path '{}' leaves the cartridge", asset));
+                }
+                assets.insert(asset.to_string(), bytes);
+            }
+        }
+
+        let mut cartridge = header.ok_or_else(|| anyhow!("{} is missing", HEADER_FILE))?;
+        if cartridge.format_version > CARTRIDGE_VERSION {
+            return Err(ArcadeError::InvalidConfig(format!(
+                "cartridge format {} was made by portfolio-arcade {}, update to read it",
+                cartridge.format_version, cartridge.tool_version
+            ))
+            .into());
+        }
+        cartridge.config.theme = cartridge.theme.clone();
+        cartridge.assets = assets;
+        Ok(cartridge)
+    }
+
+    // the config as portfolio.json and the assets under public/, the layout pack --config reads
+    pub fn unpack_to(&self, dir: &Path) -> Result<()> {
+        fs::create_dir_all(dir)?;
+        fs::write(
+            dir.join("portfolio.json"),
+            serde_json::to_string_pretty(&self.config)?,
+        )?;
+        self.write_assets(&dir.join("public"))
+    }
+
+    // writes the assets where a generated portfolio serves them from
+    pub fn write_assets(&self, public_dir: &Path) -> Result<()> {
+        for (asset, bytes) in &self.assets {
+            let path = public_dir.join(
+                safe_relative(asset).ok_or_else(|| anyhow!("invalid asset path '{}'", asset))?,
+            );
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, bytes)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+        }
+        Ok(())
+    }
+}
+
+// fixed metadata, a cartridge shouldn't carry the packer's file modes or timestamps
+fn append<W: std::io::Write>(
+    archive: &mut tar::Builder<W>,
+    path: &str,
+    bytes: &[u8],
+) -> Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(bytes.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(0);
+    header.set_cksum();
+    archive.append_data(&mut header, path, bytes)?;
+    Ok(())
+}
+
+fn is_local(path: &str) -> bool {
+    !path.is_empty()
+        && !path.contains("://")
+        && !path.starts_with("//")
+        && !path.starts_with("data:")
+}
+
+// a relative path made only of normal components
+fn safe_relative(path: &str) -> Option<PathBuf> {
+    let path = Path::new(path.trim_start_matches('/'));
+    let safe = path.components().count() > 0
+        && path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+    safe.then(|| path.to_path_buf())
+}
@@ -0,0 +1,150 @@
+// user-level defaults from ~/.config/portfolio-arcade/config.toml
+// pre-fills every generation so repeat users don't retype their details
+
+use crate::error::ArcadeError;
+use crate::models::portfolio::{CmsType, PortfolioConfig, SocialLinks};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::process::Command;
+
+// points at another defaults file, mostly for scripts and tests
+pub const DEFAULTS_PATH_ENV: &str = "PORTFOLIO_ARCADE_CONFIG";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct UserDefaults {
+    pub theme: Option<String>,
+    pub cms: Option<CmsType>,
+    pub port: Option<u16>,
+    pub author: AuthorDefaults,
+    // replaces the placeholder links outright, so a missing platform means no link
+    pub social: Option<SocialLinks>,
+    pub github: GitHubDefaults,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AuthorDefaults {
+    pub name: Option<String>,
+    pub title: Option<String>,
+    pub bio: Option<String>,
+    pub avatar: Option<String>,
+}
+
+// where the github token comes from when GITHUB_TOKEN isn't set
+// tried in order: env var, file, command
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct GitHubDefaults {
+    pub token_env: Option<String>,
+    pub token_file: Option<PathBuf>,
+    pub token_command: Option<String>,
+}
+
+impl UserDefaults {
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = std::env::var_os(DEFAULTS_PATH_ENV) {
+            return Some(PathBuf::from(path));
+        }
+        dirs::home_dir().map(|home| {
+            home.join(".config")
+                .join("portfolio-arcade")
+                .join("config.toml")
+        })
+    }
+
+    // a missing file just means no defaults
+    pub fn load() -> Result<Self> {
+        let path = match Self::path() {
+            Some(path) if path.exists() => path,
+            _ => return Ok(Self::default()),
+        };
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        toml::from_str(&content).map_err(|e| {
+            ArcadeError::InvalidConfig(format!("defaults file {}: {}", path.display(), e)).into()
+        })
+    }
+
+    // a default config with these defaults applied
+    pub fn config(&self) -> PortfolioConfig {
+        let mut config = PortfolioConfig::default();
+        self.apply(&mut config);
+        config
+    }
+
+    pub fn apply(&self, config: &mut PortfolioConfig) {
+        if let Some(theme) = &self.theme {
+            config.theme = theme.clone();
+        }
+        if let Some(cms) = &self.cms {
+            config.cms = cms.clone();
+        }
+        if let Some(port) = self.port {
+            config.dev_port = port;
+        }
+
+        let user = &mut config.user;
+        for (value, field) in [
+            (&self.author.name, &mut user.name),
+            (&self.author.title, &mut user.title),
+            (&self.author.bio, &mut user.bio),
+            (&self.author.avatar, &mut user.avatar),
+        ] {
+            if let Some(value) = value {
+                *field = value.clone();
+            }
+        }
+
+        if let Some(social) = &self.social {
+            user.social = social.clone();
+        }
+    }
+}
+
+impl GitHubDefaults {
+    // the token plus which setting it came from, for messages
+    pub fn token_with_source(&self) -> Option<(String, String)> {
+        let non_empty = |token: String| {
+            let token = token.trim().to_string();
+            (!token.is_empty()).then_some(token)
+        };
+
+        if let Some((var, token)) = self.token_env.as_ref().and_then(|var| {
+            std::env::var(var)
+                .ok()
+                .and_then(non_empty)
+                .map(|token| (var, token))
+        }) {
+            return Some((token, format!("${} (token_env)", var)));
+        }
+
+        if let Some((path, token)) = self.token_file.as_ref().and_then(|path| {
+            std::fs::read_to_string(expand_home(path))
+                .ok()
+                .and_then(non_empty)
+                .map(|token| (path, token))
+        }) {
+            return Some((token, format!("token_file {}", path.display())));
+        }
+
+        // e.g. `gh auth token`
+        let command = self.token_command.as_ref()?;
+        let mut parts = command.split_whitespace();
+        let output = Command::new(parts.next()?).args(parts).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        non_empty(String::from_utf8_lossy(&output.stdout).to_string())
+            .map(|token| (token, format!("token_command `{}`", command)))
+    }
+}
+
+fn expand_home(path: &std::path::Path) -> PathBuf {
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
@@ -1,7 +1,17 @@
 // data models for portfolio configuration and themes
 
+pub mod cartridge;
+pub mod defaults;
 pub mod portfolio;
+pub mod post;
+pub mod resume;
+pub mod roster;
 pub mod theme;
 
+pub use cartridge::*;
+pub use defaults::*;
 pub use portfolio::*;
+pub use post::*;
+pub use resume::*;
+pub use roster::*;
 pub use theme::*;
@@ -1,13 +1,20 @@
 // portfolio configuration data structures
 // represents all the user info and settings needed for generation
 
+use crate::models::post::{sort_posts, Post};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PortfolioConfig {
     pub user: UserInfo,
     pub projects: Vec<Project>,
+    pub skills: Vec<Skill>,
+    pub experience: Vec<TimelineEntry>,
+    pub education: Vec<TimelineEntry>,
+    pub posts: Vec<Post>,
     pub theme: String, // "ps3", "ps5", "wii"
     pub cms: CmsType,
     pub dev_port: u16,
@@ -18,6 +25,10 @@ impl Default for PortfolioConfig {
         Self {
             user: UserInfo::default(),
             projects: vec![create_sample_project()],
+            skills: Vec::new(),
+            experience: Vec::new(),
+            education: Vec::new(),
+            posts: Vec::new(),
             theme: "ps5".to_string(), // default to most modern theme
             cms: CmsType::Decap,      // recommended for beginners
             dev_port: 3000,
@@ -25,7 +36,34 @@ impl Default for PortfolioConfig {
     }
 }
 
+impl PortfolioConfig {
+    // adds skills that aren't listed yet, matched by name ignoring case
+    // skills already in the config keep their category and proficiency
+    pub fn add_skills(&mut self, skills: Vec<Skill>) {
+        for skill in skills {
+            if !self
+                .skills
+                .iter()
+                .any(|s| s.name.eq_ignore_ascii_case(&skill.name))
+            {
+                self.skills.push(skill);
+            }
+        }
+    }
+
+    // adds posts read from markdown, replacing listed posts with the same slug
+    pub fn add_posts(&mut self, posts: Vec<Post>) {
+        for post in posts {
+            let slug = post.file_slug();
+            self.posts.retain(|p| p.file_slug() != slug);
+            self.posts.push(post);
+        }
+        sort_posts(&mut self.posts);
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct UserInfo {
     pub name: String,
     pub title: String,
@@ -46,28 +84,273 @@ impl Default for UserInfo {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SocialLinks {
-    pub github: Option<String>,
-    pub linkedin: Option<String>,
-    pub email: Option<String>,
-    pub website: Option<String>,
-    pub twitter: Option<String>,
-}
+// profile links in the order the portfolio shows them
+// configs from before the list still load, see SocialLinks::try_from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Value")]
+pub struct SocialLinks(pub Vec<SocialLink>);
 
 impl Default for SocialLinks {
     fn default() -> Self {
+        Self(vec![
+            SocialLink::new(SocialPlatform::Github, "https://github.com/username"),
+            SocialLink::new(SocialPlatform::Linkedin, "https://linkedin.com/in/username"),
+            SocialLink::new(SocialPlatform::Email, "mailto:hello@example.com"),
+        ])
+    }
+}
+
+impl std::ops::Deref for SocialLinks {
+    type Target = Vec<SocialLink>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for SocialLinks {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl SocialLinks {
+    // the first link on a platform
+    pub fn find(&self, platform: SocialPlatform) -> Option<&SocialLink> {
+        self.iter().find(|link| link.platform == platform)
+    }
+
+    pub fn email(&self) -> Option<&str> {
+        self.iter().find_map(SocialLink::email)
+    }
+}
+
+// the fixed fields social links had before they became a list
+#[derive(Deserialize)]
+struct FixedSocialLinks {
+    github: Option<String>,
+    linkedin: Option<String>,
+    email: Option<String>,
+    website: Option<String>,
+    twitter: Option<String>,
+}
+
+impl TryFrom<Value> for SocialLinks {
+    type Error = serde_json::Error;
+
+    // a list of links, or the old object with one optional field per platform
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let links: Vec<SocialLink> = match value {
+            Value::Object(_) => {
+                let fixed: FixedSocialLinks = serde_json::from_value(value)?;
+                [
+                    (SocialPlatform::Github, fixed.github),
+                    (SocialPlatform::Linkedin, fixed.linkedin),
+                    (SocialPlatform::Email, fixed.email),
+                    (SocialPlatform::Website, fixed.website),
+                    (SocialPlatform::Twitter, fixed.twitter),
+                ]
+                .into_iter()
+                .filter_map(|(platform, url)| {
+                    let url = url.filter(|url| !url.trim().is_empty())?;
+                    Some(SocialLink::new(platform, url))
+                })
+                .collect()
+            }
+            value => serde_json::from_value(value)?,
+        };
+
+        // labels are optional in configs, the platform name stands in
+        Ok(Self(
+            links
+                .into_iter()
+                .map(|mut link| {
+                    if link.label.trim().is_empty() {
+                        link.label = link.platform.display_name().to_string();
+                    }
+                    link
+                })
+                .collect(),
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SocialLink {
+    pub platform: SocialPlatform,
+    // email links use a mailto: url
+    pub url: String,
+    #[serde(default)]
+    pub label: String,
+}
+
+impl SocialLink {
+    pub fn new(platform: SocialPlatform, url: impl Into<String>) -> Self {
+        let mut url = url.into();
+        if platform == SocialPlatform::Email && !url.starts_with("mailto:") {
+            url = format!("mailto:{}", url);
+        }
         Self {
-            github: Some("https://github.com/username".to_string()),
-            linkedin: Some("https://linkedin.com/in/username".to_string()),
-            email: Some("hello@example.com".to_string()),
-            website: None,
-            twitter: None,
+            platform,
+            url,
+            label: platform.display_name().to_string(),
+        }
+    }
+
+    // a link on whichever platform the url belongs to
+    pub fn from_url(url: &str) -> Self {
+        Self::new(SocialPlatform::from_url(url), url.trim())
+    }
+
+    // the address of an email link
+    pub fn email(&self) -> Option<&str> {
+        match self.platform {
+            SocialPlatform::Email => Some(self.url.trim_start_matches("mailto:")),
+            _ => None,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SocialPlatform {
+    Github,
+    Linkedin,
+    Email,
+    Website,
+    Twitter,
+    Mastodon,
+    Bluesky,
+    Youtube,
+    Itchio,
+    Devto,
+    Custom, // anything else, named by its label
+}
+
+impl SocialPlatform {
+    pub const ALL: [SocialPlatform; 11] = [
+        SocialPlatform::Github,
+        SocialPlatform::Linkedin,
+        SocialPlatform::Email,
+        SocialPlatform::Website,
+        SocialPlatform::Twitter,
+        SocialPlatform::Mastodon,
+        SocialPlatform::Bluesky,
+        SocialPlatform::Youtube,
+        SocialPlatform::Itchio,
+        SocialPlatform::Devto,
+        SocialPlatform::Custom,
+    ];
+
+    // the id used in portfolio.json
+    pub fn id(&self) -> &'static str {
+        match self {
+            SocialPlatform::Github => "github",
+            SocialPlatform::Linkedin => "linkedin",
+            SocialPlatform::Email => "email",
+            SocialPlatform::Website => "website",
+            SocialPlatform::Twitter => "twitter",
+            SocialPlatform::Mastodon => "mastodon",
+            SocialPlatform::Bluesky => "bluesky",
+            SocialPlatform::Youtube => "youtube",
+            SocialPlatform::Itchio => "itchio",
+            SocialPlatform::Devto => "devto",
+            SocialPlatform::Custom => "custom",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            SocialPlatform::Github => "GitHub",
+            SocialPlatform::Linkedin => "LinkedIn",
+            SocialPlatform::Email => "Email",
+            SocialPlatform::Website => "Website",
+            SocialPlatform::Twitter => "Twitter",
+            SocialPlatform::Mastodon => "Mastodon",
+            SocialPlatform::Bluesky => "Bluesky",
+            SocialPlatform::Youtube => "YouTube",
+            SocialPlatform::Itchio => "itch.io",
+            SocialPlatform::Devto => "Dev.to",
+            SocialPlatform::Custom => "Link",
+        }
+    }
+
+    // guesses the platform from a link, unknown sites count as a website
+    pub fn from_url(url: &str) -> Self {
+        let url = url.trim().to_lowercase();
+        if url.starts_with("mailto:") || (url.contains('@') && !url.contains("://")) {
+            return SocialPlatform::Email;
+        }
+
+        let rest = url.split_once("://").map_or(url.as_str(), |(_, rest)| rest);
+        let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+        let host = host.trim_start_matches("www.");
+        match host {
+            "github.com" => SocialPlatform::Github,
+            "linkedin.com" => SocialPlatform::Linkedin,
+            "twitter.com" | "x.com" => SocialPlatform::Twitter,
+            "bsky.app" => SocialPlatform::Bluesky,
+            "youtube.com" | "youtu.be" => SocialPlatform::Youtube,
+            "dev.to" => SocialPlatform::Devto,
+            _ if host == "itch.io" || host.ends_with(".itch.io") => SocialPlatform::Itchio,
+            // mastodon runs on many servers, profiles all live at /@user
+            _ if path.starts_with('@') => SocialPlatform::Mastodon,
+            _ => SocialPlatform::Website,
+        }
+    }
+}
+
+impl std::str::FromStr for SocialPlatform {
+    type Err = String;
+
+    // platform ids, plus the names json resume profiles tend to use
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "x" => Ok(SocialPlatform::Twitter),
+            "itch.io" | "itch" => Ok(SocialPlatform::Itchio),
+            "dev.to" | "dev" => Ok(SocialPlatform::Devto),
+            "mail" => Ok(SocialPlatform::Email),
+            other => SocialPlatform::ALL
+                .into_iter()
+                .find(|platform| platform.id() == other)
+                .ok_or_else(|| format!("unknown social platform '{}'", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Skill {
+    pub name: String,
+    pub category: String, // "language", "framework", "tool", ...
+    pub proficiency: u8,  // 0-100
+}
+
+// a job in experience, or a degree or course in education, newest first
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimelineEntry {
+    pub organization: String,
+    pub role: String, // job title, or degree and field of study
+    pub start_date: String,
+    pub end_date: String, // empty while it's ongoing
+    pub highlights: Vec<String>,
+}
+
+impl TimelineEntry {
+    // "2019 - 2021", "2022 - present", or empty without a start date
+    pub fn period(&self) -> String {
+        match (self.start_date.is_empty(), self.end_date.is_empty()) {
+            (true, _) => String::new(),
+            (false, true) => format!("{} - present", self.start_date),
+            (false, false) => format!("{} - {}", self.start_date, self.end_date),
+        }
+    }
+}
+
+// the trait impls below wrap these derives to migrate older configs
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, remote = "Self")]
 pub struct Project {
     pub id: String,
     pub title: String,
@@ -80,12 +363,94 @@ pub struct Project {
     pub date: String,
     pub thumbnail: String,
     pub screenshots: Vec<String>,
-    // theme-specific data will be added by adapters
+    // filled from github for imported repos, None for hand-described projects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo: Option<RepoMetadata>,
+    // user-defined fields, passed through by adapters without replacing their own keys
     #[serde(flatten)]
     pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl Project {
+    pub fn stars(&self) -> u32 {
+        self.repo.as_ref().map_or(0, |repo| repo.stars)
+    }
+
+    // configs from before RepoMetadata kept stars, forks and comma-joined topics in extra
+    // next to them, the other repo keys are github's too and move along
+    fn lift_repo_metadata(&mut self) {
+        if self.repo.is_some() {
+            return;
+        }
+
+        let mut repo = RepoMetadata::default();
+        let mut lifted = false;
+        for (key, count) in [("stars", &mut repo.stars), ("forks", &mut repo.forks)] {
+            if let Some(value) = self.extra.get(key).and_then(|v| v.as_u64()) {
+                *count = u32::try_from(value).unwrap_or(u32::MAX);
+                self.extra.remove(key);
+                lifted = true;
+            }
+        }
+        if let Some(topics) = self.extra.get("topics").and_then(|v| v.as_str()) {
+            repo.topics = topics
+                .split(',')
+                .map(str::trim)
+                .filter(|topic| !topic.is_empty())
+                .map(str::to_string)
+                .collect();
+            self.extra.remove("topics");
+            lifted = true;
+        }
+
+        if !lifted {
+            return;
+        }
+
+        let mut take_str = |key: &str| match self.extra.remove(key) {
+            Some(serde_json::Value::String(value)) => Some(value),
+            _ => None,
+        };
+        repo.language = take_str("language");
+        repo.pushed_at = take_str("pushedAt");
+        repo.license = take_str("license");
+        if let Some(archived) = self.extra.remove("archived") {
+            repo.archived = archived.as_bool().unwrap_or(false);
+        }
+        self.repo = Some(repo);
+    }
+}
+
+impl Serialize for Project {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Project::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Project {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut project = Project::deserialize(deserializer)?;
+        project.lift_repo_metadata();
+        Ok(project)
+    }
+}
+
+// what github reports about a project's repository, refreshed by sync
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RepoMetadata {
+    pub stars: u32,
+    pub forks: u32,
+    pub topics: Vec<String>,
+    // the language most of the code is written in
+    pub language: Option<String>,
+    pub pushed_at: Option<String>,
+    // spdx id, or the license name when github has none
+    pub license: Option<String>,
+    pub archived: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProjectLinks {
     pub github: Option<String>,
     pub live: Option<String>,
@@ -94,9 +459,29 @@ pub struct ProjectLinks {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CmsType {
-    Decap,   // git-based cms (netlify cms successor)
+    #[serde(alias = "decap")]
+    Decap, // git-based cms (netlify cms successor)
+    #[serde(alias = "payload")]
     Payload, // self-hosted headless cms
-    None,    // manual json editing
+    #[serde(alias = "none")]
+    None, // manual json editing
+}
+
+impl std::str::FromStr for CmsType {
+    type Err = String;
+
+    // parses the lowercase names used on the command line and in portfolio.json
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "decap" => Ok(CmsType::Decap),
+            "payload" => Ok(CmsType::Payload),
+            "none" => Ok(CmsType::None),
+            other => Err(format!(
+                "unknown cms '{}' (expected decap, payload or none)",
+                other
+            )),
+        }
+    }
 }
 
 impl std::fmt::Display for CmsType {
@@ -135,6 +520,7 @@ fn create_sample_project() -> Project {
             "/projects/portfolio-1.jpg".to_string(),
             "/projects/portfolio-2.jpg".to_string(),
         ],
+        repo: None,
         extra: HashMap::new(),
     }
 }
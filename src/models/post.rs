@@ -0,0 +1,197 @@
+// posts - technical articles authored as markdown under content/posts/
+// frontmatter holds the title, date, summary, tags and an optional link to where it's published
+
+use crate::error::ArcadeError;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+// where posts live, relative to a generated portfolio or a config file
+pub const POSTS_DIR: &str = "content/posts";
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Post {
+    // the markdown file name without .md, derived from the title when empty
+    pub slug: String,
+    pub title: String,
+    pub date: String,
+    pub summary: String,
+    // published elsewhere, the body is optional then
+    pub url: Option<String>,
+    // markdown, without the frontmatter
+    pub body: String,
+    pub tags: Vec<String>,
+}
+
+impl Post {
+    pub fn file_slug(&self) -> String {
+        if !self.slug.is_empty() {
+            return self.slug.clone();
+        }
+        let slug: String = self
+            .title
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+        slug.split('-')
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+
+    pub fn file_name(&self) -> String {
+        format!("{}.md", self.file_slug())
+    }
+
+    // values are written as json, which yaml frontmatter readers accept too
+    pub fn to_markdown(&self) -> String {
+        let quote = |s: &str| serde_json::to_string(s).unwrap_or_default();
+        let mut markdown = String::from("---\n");
+        markdown.push_str(&format!("title: {}\n", quote(&self.title)));
+        if !self.date.is_empty() {
+            markdown.push_str(&format!("date: {}\n", quote(&self.date)));
+        }
+        if !self.summary.is_empty() {
+            markdown.push_str(&format!("summary: {}\n", quote(&self.summary)));
+        }
+        if let Some(url) = &self.url {
+            markdown.push_str(&format!("url: {}\n", quote(url)));
+        }
+        markdown.push_str(&format!(
+            "tags: {}\n",
+            serde_json::to_string(&self.tags).unwrap_or_default()
+        ));
+        markdown.push_str("---\n");
+        if !self.body.is_empty() {
+            markdown.push('\n');
+            markdown.push_str(&self.body);
+            markdown.push('\n');
+        }
+        markdown
+    }
+
+    // reads the frontmatter subset posts use: scalars, [inline] and "- item" lists
+    pub fn from_markdown(slug: &str, markdown: &str) -> Result<Self, String> {
+        let rest = markdown
+            .trim_start_matches('\u{feff}')
+            .strip_prefix("---")
+            .ok_or("missing the --- frontmatter block")?;
+        let (frontmatter, body) = match rest.find("\n---") {
+            Some(end) => (&rest[..end], &rest[end + 4..]),
+            None => return Err("the frontmatter block is never closed".to_string()),
+        };
+
+        let mut post = Post {
+            slug: slug.to_string(),
+            body: body
+                .trim_start_matches(|c| c != '\n')
+                .trim_start_matches(['\r', '\n'])
+                .trim_end()
+                .to_string(),
+            ..Default::default()
+        };
+
+        let mut list_key: Option<String> = None;
+        for line in frontmatter.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            if let (Some(item), Some(key)) = (trimmed.strip_prefix("- "), &list_key) {
+                if key == "tags" {
+                    post.tags.push(scalar(item));
+                }
+                continue;
+            }
+
+            let (key, value) = trimmed
+                .split_once(':')
+                .ok_or_else(|| format!("can't read frontmatter line '{}'", trimmed))?;
+            let (key, value) = (key.trim(), value.trim());
+            list_key = value.is_empty().then(|| key.to_string());
+            match key {
+                "title" => post.title = scalar(value),
+                "date" => post.date = scalar(value),
+                "summary" | "description" => post.summary = scalar(value),
+                "url" | "link" => post.url = Some(scalar(value)).filter(|url| !url.is_empty()),
+                "tags" if !value.is_empty() => post.tags = inline_list(value),
+                _ => {}
+            }
+        }
+
+        if post.title.trim().is_empty() {
+            return Err("the frontmatter has no title".to_string());
+        }
+        Ok(post)
+    }
+
+    // every post in a directory, newest first
+    // a missing directory just means there are no posts
+    pub fn load_dir(dir: &Path) -> Result<Vec<Post>> {
+        if !dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut posts = Vec::new();
+        for entry in
+            fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))?
+        {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("md") {
+                continue;
+            }
+            let slug = path
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let markdown = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            let post = Post::from_markdown(&slug, &markdown)
+                .map_err(|e| ArcadeError::InvalidConfig(format!("{}: {}", path.display(), e)))?;
+            posts.push(post);
+        }
+
+        sort_posts(&mut posts);
+        Ok(posts)
+    }
+}
+
+// newest first, iso dates sort as text
+pub fn sort_posts(posts: &mut [Post]) {
+    posts.sort_by(|a, b| {
+        b.date
+            .cmp(&a.date)
+            .then_with(|| a.file_slug().cmp(&b.file_slug()))
+    });
+}
+
+// a quoted or bare frontmatter value
+fn scalar(value: &str) -> String {
+    let value = value.trim();
+    if value.starts_with('"') {
+        if let Ok(parsed) = serde_json::from_str::<String>(value) {
+            return parsed;
+        }
+    }
+    match value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')) {
+        Some(inner) => inner.replace("''", "'"),
+        None => value.trim_matches('"').to_string(),
+    }
+}
+
+// ["a", "b"] or [a, b]
+fn inline_list(value: &str) -> Vec<String> {
+    if let Ok(list) = serde_json::from_str::<Vec<String>>(value) {
+        return list;
+    }
+    value
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(scalar)
+        .filter(|item| !item.is_empty())
+        .collect()
+}
@@ -0,0 +1,540 @@
+// json resume (jsonresume.org) documents and their mapping onto the portfolio model
+// only the sections the portfolio can use are modelled, the rest are reported as skipped
+
+use crate::models::portfolio::{
+    PortfolioConfig, Project, ProjectLinks, Skill, SocialLink, SocialLinks, SocialPlatform,
+    TimelineEntry,
+};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+// schema reference written into exported documents
+const JSON_RESUME_SCHEMA: &str =
+    "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JsonResume {
+    #[serde(rename = "$schema", skip_serializing_if = "String::is_empty")]
+    pub schema: String,
+    pub basics: ResumeBasics,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub work: Vec<ResumeWork>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub education: Vec<ResumeEducation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<ResumeProject>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skills: Vec<ResumeSkill>,
+    // volunteer, awards, publications and the other sections a portfolio has no place for
+    #[serde(flatten, skip_serializing)]
+    pub unmapped: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResumeBasics {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub label: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub image: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub email: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub summary: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub profiles: Vec<ResumeProfile>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResumeProfile {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub network: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub username: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ResumeWork {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub position: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub start_date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub end_date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub summary: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ResumeEducation {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub institution: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub area: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub study_type: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub start_date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub end_date: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub courses: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ResumeProject {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub highlights: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub start_date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub end_date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    #[serde(rename = "type", skip_serializing_if = "String::is_empty")]
+    pub kind: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResumeSkill {
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub level: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+}
+
+impl JsonResume {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read json resume {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse json resume {}", path.display()))
+    }
+
+    // sections with content that into_config drops, meta is only about the document itself
+    pub fn skipped_sections(&self) -> Vec<String> {
+        self.unmapped
+            .iter()
+            .filter(|(key, value)| {
+                let empty = match value {
+                    serde_json::Value::Null => true,
+                    serde_json::Value::Array(items) => items.is_empty(),
+                    serde_json::Value::Object(map) => map.is_empty(),
+                    serde_json::Value::String(s) => s.is_empty(),
+                    _ => false,
+                };
+                key.as_str() != "meta" && !empty
+            })
+            .map(|(key, _)| key.clone())
+            .collect()
+    }
+
+    // layers the resume on top of an existing config
+    // sections missing from the resume keep the config's values
+    pub fn into_config(self, mut config: PortfolioConfig) -> PortfolioConfig {
+        let basics = self.basics;
+
+        if !basics.name.is_empty() {
+            config.user.name = basics.name;
+        }
+        // fall back to the most recent position when there's no headline
+        if !basics.label.is_empty() {
+            config.user.title = basics.label;
+        } else if let Some(work) = self.work.first().filter(|w| !w.position.is_empty()) {
+            config.user.title = work.position.clone();
+        }
+        if !basics.summary.is_empty() {
+            config.user.bio = basics.summary;
+        }
+        if !basics.image.is_empty() {
+            config.user.avatar = basics.image;
+        }
+
+        // a resume replaces the placeholder links rather than mixing with them
+        let has_links =
+            !basics.email.is_empty() || !basics.url.is_empty() || !basics.profiles.is_empty();
+        if has_links {
+            config.user.social = social_from_basics(&basics.email, &basics.url, &basics.profiles);
+        }
+
+        if !self.projects.is_empty() {
+            config.projects = self
+                .projects
+                .into_iter()
+                .enumerate()
+                .map(|(i, project)| project.into_project(i))
+                .collect();
+        }
+
+        // each keyword is a skill, the section name its category
+        if !self.skills.is_empty() {
+            config.skills = self
+                .skills
+                .into_iter()
+                .flat_map(ResumeSkill::into_skills)
+                .collect();
+        }
+
+        if !self.work.is_empty() {
+            config.experience = self.work.into_iter().map(ResumeWork::into_entry).collect();
+        }
+        if !self.education.is_empty() {
+            config.education = self
+                .education
+                .into_iter()
+                .map(ResumeEducation::into_entry)
+                .collect();
+        }
+
+        config
+    }
+
+    // builds a resume document from a portfolio config
+    pub fn from_config(config: &PortfolioConfig) -> Self {
+        let user = &config.user;
+        let social = &user.social;
+
+        // email and website have their own basics fields, every other link is a profile
+        let profiles = social
+            .iter()
+            .filter(|link| {
+                !matches!(
+                    link.platform,
+                    SocialPlatform::Email | SocialPlatform::Website
+                )
+            })
+            .map(|link| ResumeProfile {
+                network: link.label.clone(),
+                username: username_from_url(&link.url),
+                url: link.url.clone(),
+            })
+            .collect();
+
+        Self {
+            schema: JSON_RESUME_SCHEMA.to_string(),
+            basics: ResumeBasics {
+                name: user.name.clone(),
+                label: user.title.clone(),
+                // relative avatar paths only make sense inside the generated site
+                image: if user.avatar.starts_with("http") {
+                    user.avatar.clone()
+                } else {
+                    String::new()
+                },
+                email: social.email().unwrap_or_default().to_string(),
+                url: social
+                    .find(SocialPlatform::Website)
+                    .map(|link| link.url.clone())
+                    .unwrap_or_default(),
+                summary: user.bio.clone(),
+                profiles,
+            },
+            work: config
+                .experience
+                .iter()
+                .map(ResumeWork::from_entry)
+                .collect(),
+            education: config
+                .education
+                .iter()
+                .map(ResumeEducation::from_entry)
+                .collect(),
+            projects: config
+                .projects
+                .iter()
+                .map(ResumeProject::from_project)
+                .collect(),
+            skills: ResumeSkill::from_skills(&config.skills),
+            unmapped: BTreeMap::new(),
+        }
+    }
+}
+
+impl ResumeSkill {
+    // one section per category, levelled by its strongest skill
+    fn from_skills(skills: &[Skill]) -> Vec<Self> {
+        let mut sections: Vec<Self> = Vec::new();
+        let mut strongest: Vec<u8> = Vec::new();
+        for skill in skills {
+            match sections.iter().position(|s| s.name == skill.category) {
+                Some(i) => {
+                    sections[i].keywords.push(skill.name.clone());
+                    strongest[i] = strongest[i].max(skill.proficiency);
+                }
+                None => {
+                    sections.push(Self {
+                        name: skill.category.clone(),
+                        level: String::new(),
+                        keywords: vec![skill.name.clone()],
+                    });
+                    strongest.push(skill.proficiency);
+                }
+            }
+        }
+        for (section, proficiency) in sections.iter_mut().zip(strongest) {
+            section.level = level_name(proficiency).to_string();
+        }
+        sections
+    }
+
+    // a section without keywords is a single skill
+    fn into_skills(self) -> Vec<Skill> {
+        let proficiency = level_proficiency(&self.level);
+        if self.keywords.is_empty() {
+            return vec![Skill {
+                name: self.name,
+                category: "general".to_string(),
+                proficiency,
+            }];
+        }
+        self.keywords
+            .into_iter()
+            .map(|keyword| Skill {
+                name: keyword,
+                category: self.name.clone(),
+                proficiency,
+            })
+            .collect()
+    }
+}
+
+fn level_name(proficiency: u8) -> &'static str {
+    match proficiency {
+        80.. => "expert",
+        60..=79 => "advanced",
+        40..=59 => "intermediate",
+        _ => "beginner",
+    }
+}
+
+// json resume levels are free text, the common words map onto proficiency
+fn level_proficiency(level: &str) -> u8 {
+    let level = level.trim().to_lowercase();
+    match level.as_str() {
+        "master" | "expert" => 90,
+        "advanced" => 70,
+        "intermediate" => 50,
+        "beginner" | "novice" | "basic" => 25,
+        _ => level
+            .trim_end_matches('%')
+            .parse::<u8>()
+            .map(|p| p.min(100))
+            .unwrap_or(50),
+    }
+}
+
+impl ResumeWork {
+    fn from_entry(entry: &TimelineEntry) -> Self {
+        Self {
+            name: entry.organization.clone(),
+            position: entry.role.clone(),
+            start_date: iso_date(&entry.start_date).unwrap_or_default(),
+            end_date: iso_date(&entry.end_date).unwrap_or_default(),
+            highlights: entry.highlights.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn into_entry(self) -> TimelineEntry {
+        TimelineEntry {
+            organization: self.name,
+            role: self.position,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            highlights: self.highlights,
+        }
+    }
+}
+
+impl ResumeEducation {
+    // the whole degree goes in studyType, area stays empty
+    fn from_entry(entry: &TimelineEntry) -> Self {
+        Self {
+            institution: entry.organization.clone(),
+            study_type: entry.role.clone(),
+            start_date: iso_date(&entry.start_date).unwrap_or_default(),
+            end_date: iso_date(&entry.end_date).unwrap_or_default(),
+            courses: entry.highlights.clone(),
+            ..Default::default()
+        }
+    }
+
+    // "Bachelor in Computer Science", courses become the highlights
+    fn into_entry(self) -> TimelineEntry {
+        let role = match (self.study_type.is_empty(), self.area.is_empty()) {
+            (false, false) => format!("{} in {}", self.study_type, self.area),
+            (false, true) => self.study_type,
+            _ => self.area,
+        };
+        TimelineEntry {
+            organization: self.institution,
+            role,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            highlights: self.courses,
+        }
+    }
+}
+
+impl ResumeProject {
+    fn from_project(project: &Project) -> Self {
+        // bullet lines in the long description are the highlights
+        let highlights = project
+            .full_description
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("- "))
+            .map(|line| line.to_string())
+            .collect();
+
+        Self {
+            name: project.title.clone(),
+            description: project.description.clone(),
+            highlights,
+            keywords: project.tech_stack.clone(),
+            start_date: iso_date(&project.date).unwrap_or_default(),
+            end_date: String::new(),
+            url: project
+                .links
+                .live
+                .clone()
+                .or_else(|| project.links.github.clone())
+                .unwrap_or_default(),
+            kind: project.category.clone(),
+        }
+    }
+
+    fn into_project(self, index: usize) -> Project {
+        // highlights become a bullet list under the description
+        let mut full_description = self.description.clone();
+        if !self.highlights.is_empty() {
+            if !full_description.is_empty() {
+                full_description.push_str("\n\n");
+            }
+            let bullets: Vec<String> = self.highlights.iter().map(|h| format!("- {}", h)).collect();
+            full_description.push_str(&bullets.join("\n"));
+        }
+
+        let mut links = ProjectLinks::default();
+        if !self.url.is_empty() {
+            if self.url.contains("github.com") {
+                links.github = Some(self.url);
+            } else {
+                links.live = Some(self.url);
+            }
+        }
+
+        Project {
+            id: format!("project-{}", index + 1),
+            title: self.name,
+            description: self.description,
+            full_description,
+            tech_stack: self.keywords,
+            category: if self.kind.is_empty() {
+                "Development".to_string()
+            } else {
+                self.kind
+            },
+            featured: index == 0,
+            links,
+            date: self.start_date,
+            ..Default::default()
+        }
+    }
+}
+
+fn social_from_basics(email: &str, url: &str, profiles: &[ResumeProfile]) -> SocialLinks {
+    let mut links = Vec::new();
+    if !email.is_empty() {
+        links.push(SocialLink::new(SocialPlatform::Email, email));
+    }
+    if !url.is_empty() {
+        links.push(SocialLink::new(SocialPlatform::Website, url));
+    }
+
+    for profile in profiles {
+        let url = if profile.url.is_empty() {
+            profile_url(&profile.network, &profile.username)
+        } else {
+            Some(profile.url.clone())
+        };
+        let url = match url {
+            Some(url) => url,
+            None => continue,
+        };
+
+        // networks without a platform of their own are custom links named after the network
+        let link = match profile.network.parse::<SocialPlatform>() {
+            Ok(platform) => SocialLink::new(platform, url),
+            Err(_) => {
+                let platform = match SocialPlatform::from_url(&url) {
+                    SocialPlatform::Website => SocialPlatform::Custom,
+                    platform => platform,
+                };
+                SocialLink {
+                    label: profile.network.clone(),
+                    ..SocialLink::new(platform, url)
+                }
+            }
+        };
+        links.push(link);
+    }
+
+    SocialLinks(links)
+}
+
+// builds a profile url from the username for networks that need one
+fn profile_url(network: &str, username: &str) -> Option<String> {
+    if username.is_empty() {
+        return None;
+    }
+    match network.to_lowercase().as_str() {
+        "github" => Some(format!("https://github.com/{}", username)),
+        "linkedin" => Some(format!("https://linkedin.com/in/{}", username)),
+        "twitter" | "x" => Some(format!("https://twitter.com/{}", username)),
+        _ => None,
+    }
+}
+
+fn username_from_url(url: &str) -> String {
+    url.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+// json resume dates must be yyyy, yyyy-mm or yyyy-mm-dd
+fn iso_date(date: &str) -> Option<String> {
+    let re = regex::Regex::new(r"^[1-2][0-9]{3}(-[0-1][0-9](-[0-3][0-9])?)?$").unwrap();
+    re.is_match(date).then(|| date.to_string())
+}
@@ -0,0 +1,141 @@
+// rosters for generating many portfolios at once, one row per person
+// read from csv (with a header row) or from a json array of objects
+
+use crate::error::ArcadeError;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::Path;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RosterEntry {
+    // project directory name
+    pub name: String,
+    pub author: Option<String>,
+    pub title: Option<String>,
+    pub theme: Option<String>,
+    pub repos: Vec<String>,
+}
+
+// json rows accept the repos as a list or as one string like the csv column
+#[derive(Debug, Deserialize)]
+struct JsonRow {
+    #[serde(alias = "project", alias = "project_name")]
+    name: String,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    theme: Option<String>,
+    #[serde(default, alias = "github")]
+    repos: Option<RepoList>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RepoList {
+    One(String),
+    Many(Vec<String>),
+}
+
+pub struct Roster;
+
+impl Roster {
+    // csv for .csv files, json for everything else
+    pub fn load(path: &Path) -> Result<Vec<RosterEntry>> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read roster {}", path.display()))?;
+        let is_csv = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+
+        let entries = if is_csv {
+            Self::parse_csv(&content)
+        } else {
+            Self::parse_json(&content)
+        }
+        .with_context(|| format!("failed to parse roster {}", path.display()))?;
+
+        if entries.is_empty() {
+            return Err(ArcadeError::InvalidConfig(format!(
+                "roster {} has no rows",
+                path.display()
+            ))
+            .into());
+        }
+        Ok(entries)
+    }
+
+    // columns are matched by header name, in any order and case
+    // name is required, author, title, theme and repos are optional
+    pub fn parse_csv(content: &str) -> Result<Vec<RosterEntry>> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(content.as_bytes());
+
+        let headers: Vec<String> = reader
+            .headers()?
+            .iter()
+            .map(|h| h.to_lowercase().replace([' ', '-'], "_"))
+            .collect();
+        let column = |names: &[&str]| headers.iter().position(|h| names.contains(&h.as_str()));
+        let name_column = column(&["name", "project", "project_name"])
+            .ok_or_else(|| ArcadeError::InvalidConfig("roster needs a name column".to_string()))?;
+        let author_column = column(&["author"]);
+        let title_column = column(&["title"]);
+        let theme_column = column(&["theme"]);
+        let repos_column = column(&["repos", "github", "github_urls"]);
+
+        let mut entries = Vec::new();
+        for record in reader.records() {
+            let record = record?;
+            let field = |column: Option<usize>| {
+                column
+                    .and_then(|c| record.get(c))
+                    .filter(|value| !value.is_empty())
+                    .map(|value| value.to_string())
+            };
+            entries.push(RosterEntry {
+                name: field(Some(name_column)).unwrap_or_default(),
+                author: field(author_column),
+                title: field(title_column),
+                theme: field(theme_column),
+                repos: field(repos_column)
+                    .map(|repos| split_repos(&repos))
+                    .unwrap_or_default(),
+            });
+        }
+        Ok(entries)
+    }
+
+    pub fn parse_json(content: &str) -> Result<Vec<RosterEntry>> {
+        let rows: Vec<JsonRow> = serde_json::from_str(content)?;
+        Ok(rows
+            .into_iter()
+            .map(|row| RosterEntry {
+                name: row.name.trim().to_string(),
+                author: row.author.filter(|s| !s.trim().is_empty()),
+                title: row.title.filter(|s| !s.trim().is_empty()),
+                theme: row.theme.filter(|s| !s.trim().is_empty()),
+                repos: match row.repos {
+                    Some(RepoList::One(repos)) => split_repos(&repos),
+                    Some(RepoList::Many(repos)) => repos
+                        .into_iter()
+                        .map(|r| r.trim().to_string())
+                        .filter(|r| !r.is_empty())
+                        .collect(),
+                    None => Vec::new(),
+                },
+            })
+            .collect())
+    }
+}
+
+// several urls share one cell, separated by spaces, semicolons, pipes or commas
+fn split_repos(repos: &str) -> Vec<String> {
+    repos
+        .split(|c: char| c.is_whitespace() || matches!(c, ';' | '|' | ','))
+        .filter(|url| !url.is_empty())
+        .map(|url| url.to_string())
+        .collect()
+}
@@ -1,10 +1,16 @@
 // theme definitions and metadata for console templates
 // each theme knows how to adapt data for its specific template format
 
-use crate::models::portfolio::{PortfolioConfig, Project};
-use anyhow::Result;
+use crate::models::portfolio::{
+    PortfolioConfig, Project, ProjectLinks, RepoMetadata, Skill, TimelineEntry,
+};
+use crate::models::post::Post;
+use anyhow::{anyhow, Result};
 use serde_json::Value;
-use std::path::Path;
+use std::collections::HashMap;
+
+// stock artwork the ps5 adapter falls back to when a project has no thumbnail
+const PS5_PLACEHOLDER_IMAGE: &str = "https://images.unsplash.com/photo-1555099962-4199c345e5dd";
 
 #[derive(Debug, Clone)]
 pub struct Theme {
@@ -55,17 +61,291 @@ impl Theme {
         }
     }
 
-    // updates the template's layout file with user information
-    pub fn update_layout(&self, layout_path: &Path, config: &PortfolioConfig) -> Result<()> {
-        let content = std::fs::read_to_string(layout_path)?;
-        let updated = match self.id.as_str() {
+    // adapts skills to the theme's skills view:
+    // a ps3 xmb column, a ps5 profile card or a wii channel
+    pub fn adapt_skills(&self, skills: &[Skill]) -> Value {
+        let adapted = skills
+            .iter()
+            .map(|skill| match self.id.as_str() {
+                "ps5" => serde_json::json!({
+                    "name": skill.name,
+                    "category": skill.category,
+                    "level": skill.proficiency,
+                }),
+                "wii" => serde_json::json!({
+                    "name": skill.name,
+                    "channel": skill.category.replace(' ', "-").to_lowercase(),
+                    "category": skill.category,
+                    // one to five stars on the channel tile
+                    "stars": skill.proficiency.div_ceil(20).max(1),
+                    "proficiency": skill.proficiency,
+                }),
+                "ps3" => serde_json::json!({
+                    "id": format!("skill-{}", skill.name.replace(' ', "-").to_lowercase()),
+                    "label": skill.name,
+                    "subtitle": skill.category,
+                    "description": format!("{}% proficiency", skill.proficiency),
+                    "progress": skill.proficiency,
+                }),
+                _ => serde_json::to_value(skill).unwrap_or_default(),
+            })
+            .collect();
+        Value::Array(adapted)
+    }
+
+    // reads theme-specific skills back into the canonical model
+    pub fn recover_skills(&self, skills: &Value) -> Vec<Skill> {
+        let items = skills.as_array().map(Vec::as_slice).unwrap_or_default();
+        items
+            .iter()
+            .map(|item| {
+                let (name, category, proficiency) = match self.id.as_str() {
+                    "ps5" => (
+                        str_field(item, "name"),
+                        str_field(item, "category"),
+                        &item["level"],
+                    ),
+                    // data from before the category was written only has the channel slug
+                    "wii" => (
+                        str_field(item, "name"),
+                        opt_str_field(item, "category")
+                            .unwrap_or_else(|| str_field(item, "channel")),
+                        &item["proficiency"],
+                    ),
+                    "ps3" => (
+                        str_field(item, "label"),
+                        str_field(item, "subtitle"),
+                        &item["progress"],
+                    ),
+                    _ => (
+                        str_field(item, "name"),
+                        str_field(item, "category"),
+                        &item["proficiency"],
+                    ),
+                };
+                Skill {
+                    name,
+                    category,
+                    proficiency: proficiency.as_u64().unwrap_or(0).min(100) as u8,
+                }
+            })
+            .collect()
+    }
+
+    // adapts experience or education entries, section names which one for ids and categories
+    // a work history is what recruiters come for, so it ranks first for them in every theme
+    pub fn adapt_timeline(&self, entries: &[TimelineEntry], section: &str) -> Value {
+        let adapted = entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| match self.id.as_str() {
+                "ps5" => serde_json::json!({
+                    "organization": entry.organization,
+                    "role": entry.role,
+                    "period": entry.period(),
+                    "startDate": entry.start_date,
+                    "endDate": entry.end_date,
+                    "highlights": entry.highlights,
+                    "priority": {
+                        "recruiter": i + 1,
+                        "engineer": i + 5,
+                        "stranger": i + 5
+                    }
+                }),
+                "wii" => serde_json::json!({
+                    "organization": entry.organization,
+                    "role": entry.role,
+                    "period": entry.period(),
+                    "startDate": entry.start_date,
+                    "endDate": entry.end_date,
+                    "highlights": entry.highlights,
+                    "category": [section, "recruiter"],
+                }),
+                // xmb items, highlights become the bullet list under the role
+                "ps3" => serde_json::json!({
+                    "id": format!("{}-{}", section, i + 1),
+                    "label": entry.role,
+                    "subtitle": entry.organization,
+                    "date": entry.period(),
+                    "description": entry
+                        .highlights
+                        .iter()
+                        .map(|h| format!("- {}", h))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                    "startDate": entry.start_date,
+                    "endDate": entry.end_date,
+                    "icon": if section == "education" { "graduation-cap" } else { "briefcase" },
+                    "profilePriority": ["recruiter", "engineer", "stranger"],
+                }),
+                _ => serde_json::to_value(entry).unwrap_or_default(),
+            })
+            .collect();
+        Value::Array(adapted)
+    }
+
+    // reads theme-specific experience or education back into the canonical model
+    pub fn recover_timeline(&self, entries: &Value) -> Vec<TimelineEntry> {
+        let items = entries.as_array().map(Vec::as_slice).unwrap_or_default();
+        items
+            .iter()
+            .map(|item| match self.id.as_str() {
+                "ps5" | "wii" => TimelineEntry {
+                    organization: str_field(item, "organization"),
+                    role: str_field(item, "role"),
+                    start_date: str_field(item, "startDate"),
+                    end_date: str_field(item, "endDate"),
+                    highlights: str_list(item, "highlights"),
+                },
+                "ps3" => TimelineEntry {
+                    organization: str_field(item, "subtitle"),
+                    role: str_field(item, "label"),
+                    start_date: str_field(item, "startDate"),
+                    end_date: str_field(item, "endDate"),
+                    highlights: item["description"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .lines()
+                        .filter_map(|line| line.trim().strip_prefix("- "))
+                        .map(|line| line.to_string())
+                        .collect(),
+                },
+                _ => serde_json::from_value(item.clone()).unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    // adapts posts with their markdown body, url is only set for posts published elsewhere
+    pub fn adapt_posts(&self, posts: &[Post]) -> Value {
+        let adapted = posts
+            .iter()
+            .map(|post| match self.id.as_str() {
+                "ps5" => serde_json::json!({
+                    "id": post.file_slug(),
+                    "title": post.title,
+                    "date": post.date,
+                    "summary": post.summary,
+                    "url": post.url,
+                    "body": post.body,
+                    "tags": post.tags,
+                }),
+                "wii" => serde_json::json!({
+                    "id": post.file_slug(),
+                    "title": post.title,
+                    "date": post.date,
+                    "tagline": post.summary,
+                    "url": post.url,
+                    "body": post.body,
+                    "tags": post.tags,
+                    "category": ["posts"],
+                }),
+                "ps3" => {
+                    let links: Vec<Value> = post
+                        .url
+                        .iter()
+                        .map(|url| serde_json::json!({ "label": "Read", "url": url }))
+                        .collect();
+                    serde_json::json!({
+                        "id": format!("post-{}", post.file_slug()),
+                        "label": post.title,
+                        "subtitle": post.tags.join(" / "),
+                        "description": post.summary,
+                        "date": post.date,
+                        "tags": post.tags,
+                        "links": links,
+                        "body": post.body,
+                        "icon": "file",
+                    })
+                }
+                _ => serde_json::to_value(post).unwrap_or_default(),
+            })
+            .collect();
+        Value::Array(adapted)
+    }
+
+    // reads theme-specific posts back into the canonical model
+    pub fn recover_posts(&self, posts: &Value) -> Vec<Post> {
+        let items = posts.as_array().map(Vec::as_slice).unwrap_or_default();
+        items
+            .iter()
+            .map(|item| match self.id.as_str() {
+                "ps5" | "wii" => Post {
+                    slug: str_field(item, "id"),
+                    title: str_field(item, "title"),
+                    date: str_field(item, "date"),
+                    summary: str_field(
+                        item,
+                        if self.id == "wii" {
+                            "tagline"
+                        } else {
+                            "summary"
+                        },
+                    ),
+                    url: opt_str_field(item, "url"),
+                    body: str_field(item, "body"),
+                    tags: str_list(item, "tags"),
+                },
+                "ps3" => Post {
+                    slug: str_field(item, "id")
+                        .strip_prefix("post-")
+                        .unwrap_or_default()
+                        .to_string(),
+                    title: str_field(item, "label"),
+                    date: str_field(item, "date"),
+                    summary: str_field(item, "description"),
+                    url: opt_str_field(&item["links"][0], "url"),
+                    body: str_field(item, "body"),
+                    tags: str_list(item, "tags"),
+                },
+                _ => serde_json::from_value(item.clone()).unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    // builds the full public/data/portfolio.json document for this theme
+    pub fn portfolio_data(&self, config: &PortfolioConfig) -> Result<Value> {
+        Ok(serde_json::json!({
+            "user": config.user,
+            "projects": self.adapt_projects(&config.projects)?,
+            "skills": self.adapt_skills(&config.skills),
+            "experience": self.adapt_timeline(&config.experience, "experience"),
+            "education": self.adapt_timeline(&config.education, "education"),
+            "posts": self.adapt_posts(&config.posts),
+            "theme": config.theme,
+            "cms": format!("{:?}", config.cms).to_lowercase(),
+            "dev_port": config.dev_port
+        }))
+    }
+
+    // reads theme-specific project data back into the canonical model
+    // fields the adapters compute (priorities, achievements) are dropped
+    pub fn recover_projects(&self, projects: &Value) -> Result<Vec<Project>> {
+        let items = projects
+            .as_array()
+            .ok_or_else(|| anyhow!("portfolio projects must be a json array"))?;
+
+        let recovered = match self.id.as_str() {
+            "ps5" => items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| recover_from_ps5(i, item))
+                .collect(),
+            "wii" => items.iter().map(recover_from_wii).collect(),
+            "ps3" => items.iter().map(recover_from_ps3).collect(),
+            _ => serde_json::from_value(projects.clone())?,
+        };
+
+        Ok(recovered)
+    }
+
+    // fills the user's details into the template's app/layout.tsx
+    pub fn layout_content(&self, content: String, config: &PortfolioConfig) -> String {
+        match self.id.as_str() {
             "ps5" => update_ps5_layout(content, config),
             "wii" => update_wii_layout(content, config),
             "ps3" => update_ps3_layout(content, config),
             _ => content,
-        };
-        std::fs::write(layout_path, updated)?;
-        Ok(())
+        }
     }
 }
 
@@ -76,15 +356,17 @@ fn adapt_for_ps5(projects: &[Project]) -> Result<Value> {
         .enumerate()
         .map(|(i, project)| {
             // Calculate priority scores based on GitHub metrics and position
-            let stars = project.extra.get("stars")
-                .and_then(|v| v.as_u64())
-                .unwrap_or(0);
+            let stars = project.stars();
             let has_live = project.links.live.is_some();
 
             // Projects with more stars and live demos rank higher for recruiters
             let recruiter_priority = if has_live && stars > 10 { i + 1 } else { i + 5 };
             // Recent projects with good tech stacks rank higher for engineers
-            let engineer_priority = if project.tech_stack.len() > 3 { i + 1 } else { i + 3 };
+            let engineer_priority = if project.tech_stack.len() > 3 {
+                i + 1
+            } else {
+                i + 3
+            };
             // Featured projects rank higher for strangers
             let stranger_priority = if project.featured { i + 1 } else { i + 4 };
 
@@ -92,12 +374,12 @@ fn adapt_for_ps5(projects: &[Project]) -> Result<Value> {
             let has_description = !project.full_description.is_empty();
             let has_links = project.links.github.is_some() || project.links.live.is_some();
             let has_tech = !project.tech_stack.is_empty();
-            let achievements = 5 +
-                (if has_description { 2 } else { 0 }) +
-                (if has_links { 2 } else { 0 }) +
-                (if has_tech { 2 } else { 0 }) +
-                (if stars > 5 { 1 } else { 0 }) +
-                (if has_live { 2 } else { 0 });
+            let achievements = 5
+                + (if has_description { 2 } else { 0 })
+                + (if has_links { 2 } else { 0 })
+                + (if has_tech { 2 } else { 0 })
+                + (if stars > 5 { 1 } else { 0 })
+                + (if has_live { 2 } else { 0 });
 
             let total_achievements = 14;
             let progress = ((achievements as f32 / total_achievements as f32) * 100.0) as u32;
@@ -106,13 +388,16 @@ fn adapt_for_ps5(projects: &[Project]) -> Result<Value> {
             let cover_image = if !project.thumbnail.is_empty() {
                 project.thumbnail.clone()
             } else {
-                format!("https://images.unsplash.com/photo-1555099962-4199c345e5dd?w=400&h=400&fit=crop&seed={}", i)
+                format!("{}?w=400&h=400&fit=crop&seed={}", PS5_PLACEHOLDER_IMAGE, i)
             };
 
             let background_image = if !project.thumbnail.is_empty() {
                 project.thumbnail.clone()
             } else {
-                format!("https://images.unsplash.com/photo-1555099962-4199c345e5dd?w=1920&h=1080&fit=crop&seed={}", i)
+                format!(
+                    "{}?w=1920&h=1080&fit=crop&seed={}",
+                    PS5_PLACEHOLDER_IMAGE, i
+                )
             };
 
             let mut adapted = serde_json::json!({
@@ -121,6 +406,7 @@ fn adapt_for_ps5(projects: &[Project]) -> Result<Value> {
                 "subtitle": project.category,
                 "description": project.description,
                 "fullDescription": project.full_description,
+                "date": project.date,
                 "techStack": project.tech_stack,
                 "achievements": achievements,
                 "totalAchievements": total_achievements,
@@ -138,13 +424,7 @@ fn adapt_for_ps5(projects: &[Project]) -> Result<Value> {
                 }
             });
 
-            // merge any extra fields (stars, forks, topics, etc.)
-            if let Ok(Value::Object(extra_map)) = serde_json::to_value(&project.extra) {
-                if let Value::Object(ref mut adapted_map) = adapted {
-                    adapted_map.extend(extra_map);
-                }
-            }
-
+            add_project_metadata(&mut adapted, project);
             adapted
         })
         .collect();
@@ -161,11 +441,7 @@ fn adapt_for_wii(projects: &[Project]) -> Result<Value> {
             let mut categories = vec![project.category.replace(" ", "-").to_lowercase()];
 
             // Add profile-based categories
-            let stars = project
-                .extra
-                .get("stars")
-                .and_then(|v| v.as_u64())
-                .unwrap_or(0);
+            let stars = project.stars();
 
             if project.links.live.is_some() && stars > 10 {
                 categories.push("recruiter".to_string());
@@ -185,7 +461,7 @@ fn adapt_for_wii(projects: &[Project]) -> Result<Value> {
                 categories.push("open-source".to_string());
             }
 
-            serde_json::json!({
+            let mut adapted = serde_json::json!({
                 "id": project.id,
                 "title": project.title,
                 "tagline": project.description,
@@ -193,11 +469,16 @@ fn adapt_for_wii(projects: &[Project]) -> Result<Value> {
                 "techStack": project.tech_stack,
                 "liveUrl": project.links.live,
                 "githubUrl": project.links.github,
+                "demoUrl": project.links.demo,
+                "date": project.date,
+                "thumbnail": project.thumbnail,
+                "screenshots": project.screenshots,
                 "category": categories,
                 "featured": project.featured,
-                "stars": project.extra.get("stars"),
-                "forks": project.extra.get("forks"),
-            })
+            });
+
+            add_project_metadata(&mut adapted, project);
+            adapted
         })
         .collect();
 
@@ -211,11 +492,7 @@ fn adapt_for_ps3(projects: &[Project]) -> Result<Value> {
         .map(|project| {
             // Calculate profile priority based on GitHub metrics
             // All projects are shown to all profiles, but ordered differently
-            let stars = project
-                .extra
-                .get("stars")
-                .and_then(|v| v.as_u64())
-                .unwrap_or(0);
+            let stars = project.stars();
             let has_live = project.links.live.is_some();
             let tech_count = project.tech_stack.len();
 
@@ -266,16 +543,13 @@ fn adapt_for_ps3(projects: &[Project]) -> Result<Value> {
                 "date": project.date,
                 "tags": project.tech_stack,
                 "links": links,
+                "image": project.thumbnail,
+                "screenshots": project.screenshots,
                 "profilePriority": profile_priority,
+                "featured": project.featured,
             });
 
-            // Add extra GitHub metadata
-            if let Ok(Value::Object(extra_map)) = serde_json::to_value(&project.extra) {
-                if let Value::Object(ref mut adapted_map) = adapted {
-                    adapted_map.extend(extra_map);
-                }
-            }
-
+            add_project_metadata(&mut adapted, project);
             adapted
         })
         .collect();
@@ -283,6 +557,270 @@ fn adapt_for_ps3(projects: &[Project]) -> Result<Value> {
     Ok(Value::Array(adapted))
 }
 
+// repo metadata keys, written the same way by every theme
+const REPO_FIELDS: &[&str] = &[
+    "stars", "forks", "topics", "language", "pushedAt", "license", "archived",
+];
+
+// adds the typed repo metadata, then user-defined extra fields
+// extra keys never replace what the adapter or the repo metadata wrote,
+// but a repo with no language or license leaves those keys to the user
+fn add_project_metadata(adapted: &mut Value, project: &Project) {
+    let adapted_map = match adapted {
+        Value::Object(map) => map,
+        _ => return,
+    };
+
+    if let Some(repo) = &project.repo {
+        let fields = serde_json::json!({
+            "stars": repo.stars,
+            "forks": repo.forks,
+            "topics": repo.topics,
+            "language": repo.language,
+            "pushedAt": repo.pushed_at,
+            "license": repo.license,
+            "archived": repo.archived,
+        });
+        if let Value::Object(fields) = fields {
+            adapted_map.extend(fields.into_iter().filter(|(_, value)| !value.is_null()));
+        }
+    }
+
+    for (key, value) in &project.extra {
+        adapted_map
+            .entry(key.clone())
+            .or_insert_with(|| value.clone());
+    }
+}
+
+// keys written by adapt_for_ps5, anything else came from project.extra
+const PS5_FIELDS: &[&str] = &[
+    "id",
+    "title",
+    "subtitle",
+    "description",
+    "fullDescription",
+    "date",
+    "techStack",
+    "achievements",
+    "totalAchievements",
+    "progress",
+    "coverImage",
+    "backgroundImage",
+    "liveUrl",
+    "githubUrl",
+    "demoVideo",
+    "screenshots",
+    "priority",
+];
+
+// keys written by adapt_for_wii
+const WII_FIELDS: &[&str] = &[
+    "id",
+    "title",
+    "tagline",
+    "description",
+    "techStack",
+    "liveUrl",
+    "githubUrl",
+    "demoUrl",
+    "date",
+    "thumbnail",
+    "screenshots",
+    "category",
+    "featured",
+];
+
+// keys written by adapt_for_ps3, anything else came from project.extra
+const PS3_FIELDS: &[&str] = &[
+    "id",
+    "label",
+    "subtitle",
+    "description",
+    "date",
+    "tags",
+    "links",
+    "image",
+    "screenshots",
+    "profilePriority",
+    "featured",
+];
+
+// recovers a project from ps5 format
+fn recover_from_ps5(index: usize, item: &Value) -> Project {
+    let cover_image = str_field(item, "coverImage");
+    let thumbnail = if cover_image.starts_with(PS5_PLACEHOLDER_IMAGE) {
+        String::new()
+    } else {
+        cover_image
+    };
+
+    Project {
+        id: str_field(item, "id"),
+        title: str_field(item, "title"),
+        description: str_field(item, "description"),
+        full_description: str_field(item, "fullDescription"),
+        tech_stack: str_list(item, "techStack"),
+        category: str_field(item, "subtitle"),
+        // the stranger ranking is the project's position only when featured
+        featured: item["priority"]["stranger"].as_u64() == Some(index as u64 + 1),
+        links: ProjectLinks {
+            github: opt_str_field(item, "githubUrl"),
+            live: opt_str_field(item, "liveUrl"),
+            demo: opt_str_field(item, "demoVideo"),
+        },
+        date: str_field(item, "date"),
+        thumbnail,
+        screenshots: str_list(item, "screenshots"),
+        repo: recover_repo(item),
+        extra: leftover_fields(item, PS5_FIELDS),
+    }
+}
+
+// recovers a project from wii format
+fn recover_from_wii(item: &Value) -> Project {
+    // the first category is always the project's own, the rest are derived
+    let category = item["category"]
+        .as_array()
+        .and_then(|c| c.first())
+        .and_then(|c| c.as_str())
+        .unwrap_or_default()
+        .replace('-', " ");
+
+    Project {
+        id: str_field(item, "id"),
+        title: str_field(item, "title"),
+        description: str_field(item, "tagline"),
+        full_description: str_field(item, "description"),
+        tech_stack: str_list(item, "techStack"),
+        category,
+        featured: item["featured"].as_bool().unwrap_or(false),
+        links: ProjectLinks {
+            github: opt_str_field(item, "githubUrl"),
+            live: opt_str_field(item, "liveUrl"),
+            demo: opt_str_field(item, "demoUrl"),
+        },
+        date: str_field(item, "date"),
+        thumbnail: str_field(item, "thumbnail"),
+        screenshots: str_list(item, "screenshots"),
+        repo: recover_repo(item),
+        extra: leftover_fields(item, WII_FIELDS),
+    }
+}
+
+// recovers a project from ps3 format
+fn recover_from_ps3(item: &Value) -> Project {
+    let mut links = ProjectLinks::default();
+    for link in item["links"].as_array().into_iter().flatten() {
+        let url = opt_str_field(link, "url");
+        match link["label"].as_str() {
+            Some("GitHub") => links.github = url,
+            Some("Live Demo") => links.live = url,
+            Some("Demo Video") => links.demo = url,
+            _ => {}
+        }
+    }
+
+    let full_description = str_field(item, "description");
+
+    Project {
+        id: str_field(item, "id"),
+        title: str_field(item, "label"),
+        description: summarize(&full_description),
+        full_description,
+        tech_stack: str_list(item, "tags"),
+        category: str_field(item, "subtitle"),
+        featured: item["featured"].as_bool().unwrap_or(false),
+        links,
+        date: str_field(item, "date"),
+        thumbnail: str_field(item, "image"),
+        screenshots: str_list(item, "screenshots"),
+        repo: recover_repo(item),
+        extra: leftover_fields(item, PS3_FIELDS),
+    }
+}
+
+fn str_field(item: &Value, key: &str) -> String {
+    item[key].as_str().unwrap_or_default().to_string()
+}
+
+fn opt_str_field(item: &Value, key: &str) -> Option<String> {
+    item[key].as_str().map(|s| s.to_string())
+}
+
+fn str_list(item: &Value, key: &str) -> Vec<String> {
+    item[key]
+        .as_array()
+        .map(|list| {
+            list.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// a repo always writes stars and forks
+// older generated data wrote only stars and forks, or topics as a comma-joined string
+fn has_repo(item: &Value) -> bool {
+    item["stars"].is_u64() || item["forks"].is_u64() || item["topics"].is_string()
+}
+
+// reads the repo metadata back, None when the project had none
+fn recover_repo(item: &Value) -> Option<RepoMetadata> {
+    if !has_repo(item) {
+        return None;
+    }
+
+    let count = |key: &str| {
+        item[key]
+            .as_u64()
+            .map_or(0, |n| u32::try_from(n).unwrap_or(u32::MAX))
+    };
+    let topics = match item["topics"].as_str() {
+        Some(topics) => topics
+            .split(',')
+            .map(str::trim)
+            .filter(|topic| !topic.is_empty())
+            .map(str::to_string)
+            .collect(),
+        None => str_list(item, "topics"),
+    };
+
+    Some(RepoMetadata {
+        stars: count("stars"),
+        forks: count("forks"),
+        topics,
+        language: opt_str_field(item, "language"),
+        pushed_at: opt_str_field(item, "pushedAt"),
+        license: opt_str_field(item, "license"),
+        archived: item["archived"].as_bool().unwrap_or(false),
+    })
+}
+
+// collects keys the adapter didn't write itself
+// without a repo, keys like language or license are the user's own
+fn leftover_fields(item: &Value, known: &[&str]) -> HashMap<String, Value> {
+    let repo = has_repo(item);
+    item.as_object()
+        .map(|map| {
+            map.iter()
+                .filter(|(key, _)| !known.contains(&key.as_str()))
+                .filter(|(key, _)| !(repo && REPO_FIELDS.contains(&key.as_str())))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// picks the first prose line of a markdown description as a short summary
+fn summarize(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .unwrap_or_default()
+        .to_string()
+}
+
 // updates ps5 layout.tsx with user info
 fn update_ps5_layout(content: String, config: &PortfolioConfig) -> String {
     content
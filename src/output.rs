@@ -0,0 +1,88 @@
+// command output in either human-friendly or machine-readable form
+// with --json, human lines are suppressed and each command prints one json document
+
+use crate::error::ArcadeError;
+use colored::*;
+use serde_json::Value;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Output {
+    json: bool,
+}
+
+impl Output {
+    pub fn new(json: bool) -> Self {
+        Self { json }
+    }
+
+    pub fn is_json(&self) -> bool {
+        self.json
+    }
+
+    // shows the welcome banner
+    pub fn banner(&self) {
+        if self.json {
+            return;
+        }
+        println!();
+        println!(
+            "{}",
+            "╔══════════════════════════════════════════════╗".cyan()
+        );
+        println!(
+            "{}",
+            "║         portfolio arcade generator           ║".cyan()
+        );
+        println!(
+            "{}",
+            "╚══════════════════════════════════════════════╝".cyan()
+        );
+        println!();
+    }
+
+    // prints a line for humans, nothing in json mode
+    pub fn line(&self, text: impl Display) {
+        if !self.json {
+            println!("{}", text);
+        }
+    }
+
+    // prints a warning for humans, json callers get warnings in the result
+    pub fn warn(&self, text: impl Display) {
+        if !self.json {
+            eprintln!("{} {}", "warning:".yellow(), text);
+        }
+    }
+
+    // prints the command's result document in json mode
+    pub fn result(&self, mut value: Value) {
+        if !self.json {
+            return;
+        }
+        if let Value::Object(ref mut map) = value {
+            map.entry("ok").or_insert(Value::Bool(true));
+        }
+        println!("{}", value);
+    }
+
+    // reports a failed command in whichever mode is active
+    pub fn error(&self, err: &anyhow::Error, code: i32) {
+        if self.json {
+            // doctor and batch already printed their results with ok: false
+            if let Some(ArcadeError::ChecksFailed { .. } | ArcadeError::BatchFailed { .. }) =
+                err.downcast_ref::<ArcadeError>()
+            {
+                return;
+            }
+            let value = serde_json::json!({
+                "ok": false,
+                "error": format!("{:#}", err),
+                "exit_code": code,
+            });
+            println!("{}", value);
+        } else {
+            eprintln!("{} {:#}", "error:".red().bold(), err);
+        }
+    }
+}
@@ -2,7 +2,8 @@
 // orchestrates the user flow from theme selection to project generation
 
 use crate::generator::TemplateGenerator;
-use crate::models::{PortfolioConfig, Theme};
+use crate::models::{PortfolioConfig, SocialLink, SocialLinks, Theme, UserDefaults};
+use crate::utils::validation::{validate_email, validate_url};
 use anyhow::Result;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
@@ -16,6 +17,7 @@ use std::io;
 pub enum Screen {
     ThemeSelection,
     ProjectDetails,
+    SocialLinks,
     GitHubProjects,
     Confirmation,
     Progress,
@@ -27,6 +29,7 @@ pub struct App {
     pub config: PortfolioConfig,
     pub themes: Vec<Theme>,
     pub selected_theme_idx: usize,
+    pub defaults: UserDefaults,
     pub input_fields: InputFields,
     pub current_input: InputField,
     pub should_quit: bool,
@@ -39,6 +42,8 @@ pub struct InputFields {
     pub project_name: String,
     pub author_name: String,
     pub title: String,
+    pub social_links: Vec<SocialLink>,
+    pub current_social_url: String,
     pub github_projects: Vec<String>,
     pub current_github_url: String,
 }
@@ -53,14 +58,20 @@ pub enum InputField {
 }
 
 impl App {
-    pub fn new(project_name: Option<String>) -> Self {
+    pub fn new(project_name: Option<String>, defaults: UserDefaults) -> Self {
         let themes = Theme::available_themes();
-        let config = PortfolioConfig::default();
+        let config = defaults.config();
+        let selected_theme_idx = themes
+            .iter()
+            .position(|theme| theme.id == config.theme)
+            .unwrap_or(1); // default to ps5
 
         let input_fields = InputFields {
             project_name: project_name.unwrap_or_else(|| "my-portfolio".to_string()),
             author_name: config.user.name.clone(),
             title: config.user.title.clone(),
+            social_links: config.user.social.to_vec(),
+            current_social_url: String::new(),
             github_projects: Vec::new(),
             current_github_url: String::new(),
         };
@@ -69,7 +80,8 @@ impl App {
             current_screen: Screen::ThemeSelection,
             config,
             themes,
-            selected_theme_idx: 1, // default to ps5
+            selected_theme_idx,
+            defaults,
             input_fields,
             current_input: InputField::ProjectName,
             should_quit: false,
@@ -142,6 +154,7 @@ impl App {
         match self.current_screen {
             Screen::ThemeSelection => self.handle_theme_selection(key),
             Screen::ProjectDetails => self.handle_project_details(key),
+            Screen::SocialLinks => self.handle_social_links(key),
             Screen::GitHubProjects => self.handle_github_projects(key).await,
             Screen::Confirmation => self.handle_confirmation(key).await?,
             Screen::Progress => {
@@ -159,15 +172,11 @@ impl App {
 
     fn handle_theme_selection(&mut self, key: KeyCode) {
         match key {
-            KeyCode::Up => {
-                if self.selected_theme_idx > 0 {
-                    self.selected_theme_idx -= 1;
-                }
+            KeyCode::Up if self.selected_theme_idx > 0 => {
+                self.selected_theme_idx -= 1;
             }
-            KeyCode::Down => {
-                if self.selected_theme_idx < self.themes.len() - 1 {
-                    self.selected_theme_idx += 1;
-                }
+            KeyCode::Down if self.selected_theme_idx < self.themes.len() - 1 => {
+                self.selected_theme_idx += 1;
             }
             KeyCode::Enter => {
                 self.config.theme = self.themes[self.selected_theme_idx].id.clone();
@@ -211,10 +220,8 @@ impl App {
                     InputField::GitHubUrl => InputField::Title,
                 };
             }
-            KeyCode::Enter => {
-                if self.validate_basic_inputs() {
-                    self.current_screen = Screen::GitHubProjects;
-                }
+            KeyCode::Enter if self.validate_basic_inputs() => {
+                self.current_screen = Screen::SocialLinks;
             }
             KeyCode::Char(c) => {
                 self.add_char_to_current_input(c);
@@ -229,6 +236,48 @@ impl App {
         }
     }
 
+    fn handle_social_links(&mut self, key: KeyCode) {
+        self.error_message = None;
+        match key {
+            KeyCode::Enter => {
+                let url = self.input_fields.current_social_url.trim().to_string();
+                if url.is_empty() {
+                    self.current_screen = Screen::GitHubProjects;
+                    return;
+                }
+                // the platform comes from the url, anything unknown is a website
+                let link = SocialLink::from_url(&url);
+                let valid = match link.email() {
+                    Some(email) => validate_email(email),
+                    None => validate_url(&link.url),
+                };
+                match valid {
+                    Ok(()) => {
+                        self.input_fields.social_links.push(link);
+                        self.input_fields.current_social_url.clear();
+                    }
+                    Err(e) => self.error_message = Some(e),
+                }
+            }
+            KeyCode::Char(c) => {
+                self.input_fields.current_social_url.push(c);
+            }
+            // an empty field removes the last link instead
+            KeyCode::Backspace | KeyCode::Delete
+                if self.input_fields.current_social_url.is_empty() =>
+            {
+                self.input_fields.social_links.pop();
+            }
+            KeyCode::Backspace | KeyCode::Delete => {
+                self.input_fields.current_social_url.pop();
+            }
+            KeyCode::Esc => {
+                self.current_screen = Screen::ProjectDetails;
+            }
+            _ => {}
+        }
+    }
+
     async fn handle_github_projects(&mut self, key: KeyCode) {
         match key {
             KeyCode::Enter => {
@@ -251,7 +300,7 @@ impl App {
                 self.input_fields.current_github_url.pop();
             }
             KeyCode::Esc => {
-                self.current_screen = Screen::ProjectDetails;
+                self.current_screen = Screen::SocialLinks;
             }
             _ => {}
         }
@@ -312,146 +361,24 @@ impl App {
     async fn update_config_from_inputs(&mut self) {
         self.config.user.name = self.input_fields.author_name.clone();
         self.config.user.title = self.input_fields.title.clone();
-        self.config.cms = crate::models::CmsType::None; // Default to no CMS for GitHub-based workflow
-                                                        // Convert GitHub URLs to project data
-        self.config.projects = self.github_urls_to_projects().await;
-    }
-
-    async fn github_urls_to_projects(&self) -> Vec<crate::models::portfolio::Project> {
-        let client = match crate::github::GitHubClient::new() {
-            Ok(client) => client,
-            Err(e) => {
-                eprintln!("Warning: Failed to create GitHub client: {}", e);
-                eprintln!("Projects will be created with placeholder data.");
-                return self.create_placeholder_projects();
-            }
-        };
-
-        let mut projects = Vec::new();
-
-        for (i, url) in self.input_fields.github_projects.iter().enumerate() {
-            match self.fetch_github_project(&client, url, i).await {
-                Ok(project) => projects.push(project),
-                Err(e) => {
-                    eprintln!("Warning: Failed to fetch data for {}: {}", url, e);
-                    eprintln!("Creating placeholder project for this repository.");
-                    projects.push(self.create_placeholder_project(url, i));
-                }
-            }
+        self.config.user.social = SocialLinks(self.input_fields.social_links.clone());
+        // Default to no CMS for GitHub-based workflow unless the user picked one in their defaults
+        if self.defaults.cms.is_none() {
+            self.config.cms = crate::models::CmsType::None;
         }
-
-        projects
-    }
-
-    async fn fetch_github_project(
-        &self,
-        client: &crate::github::GitHubClient,
-        url: &str,
-        index: usize,
-    ) -> Result<crate::models::portfolio::Project> {
-        // Parse GitHub URL
-        let (owner, repo) = crate::github::parse_github_url(url)?;
-
-        // Fetch repository data
-        let repo_data = client.get_repo(&owner, &repo).await?;
-        let languages = client.get_languages(&owner, &repo).await?;
-
-        // Process languages into tech stack
-        let tech_stack = crate::github::process_languages(&languages);
-
-        // Try to fetch README for full description
-        let full_description = client
-            .get_readme(&owner, &repo)
-            .await
-            .unwrap_or_else(|_| repo_data.description.clone().unwrap_or_default());
-
-        // Extract date from created_at
-        let date = repo_data
-            .created_at
-            .split('T')
-            .next()
-            .unwrap_or("2024-01-01");
-
-        Ok(crate::models::portfolio::Project {
-            id: format!("project-{}", index + 1),
-            title: repo_data.name.replace(['-', '_'], " "),
-            description: repo_data.description.unwrap_or_else(|| {
-                format!(
-                    "A {} project",
-                    repo_data.language.unwrap_or_else(|| "software".to_string())
-                )
-            }),
-            full_description,
-            category: "Development".to_string(),
-            tech_stack,
-            featured: index == 0 || repo_data.stargazers_count > 10,
-            links: crate::models::portfolio::ProjectLinks {
-                github: Some(url.to_string()),
-                live: repo_data.homepage,
-                demo: None,
-            },
-            thumbnail: "".to_string(),
-            screenshots: vec![],
-            date: date.to_string(),
-            extra: {
-                let mut extra = std::collections::HashMap::new();
-                extra.insert(
-                    "stars".to_string(),
-                    serde_json::json!(repo_data.stargazers_count),
-                );
-                extra.insert(
-                    "forks".to_string(),
-                    serde_json::json!(repo_data.forks_count),
-                );
-                if !repo_data.topics.is_empty() {
-                    extra.insert(
-                        "topics".to_string(),
-                        serde_json::json!(repo_data.topics.join(", ")),
-                    );
-                }
-                extra
-            },
-        })
-    }
-
-    fn create_placeholder_projects(&self) -> Vec<crate::models::portfolio::Project> {
-        self.input_fields
-            .github_projects
-            .iter()
-            .enumerate()
-            .map(|(i, url)| self.create_placeholder_project(url, i))
-            .collect()
+        // Convert GitHub URLs to project data, seeding skills from their languages
+        let imported = self.import_github_projects().await;
+        self.config.add_skills(imported.skills());
+        self.config.projects = imported.projects;
     }
 
-    fn create_placeholder_project(
-        &self,
-        url: &str,
-        index: usize,
-    ) -> crate::models::portfolio::Project {
-        let repo_name = url
-            .split('/')
-            .next_back()
-            .unwrap_or("project")
-            .replace(".git", "");
-
-        crate::models::portfolio::Project {
-            id: format!("project-{}", index + 1),
-            title: repo_name.replace('-', " "),
-            description: format!("Project hosted at {}", url),
-            full_description: "This project will be populated with GitHub data.".to_string(),
-            category: "Development".to_string(),
-            tech_stack: vec!["GitHub".to_string()],
-            featured: index == 0,
-            links: crate::models::portfolio::ProjectLinks {
-                github: Some(url.to_string()),
-                live: None,
-                demo: None,
-            },
-            thumbnail: "".to_string(),
-            screenshots: vec![],
-            date: "2024-01-01".to_string(),
-            extra: std::collections::HashMap::new(),
+    async fn import_github_projects(&self) -> crate::github::projects::ImportedProjects {
+        let imported =
+            crate::github::projects::urls_to_projects(&self.input_fields.github_projects).await;
+        for warning in &imported.warnings {
+            eprintln!("Warning: {}", warning);
         }
+        imported
     }
 
     async fn generate_project(&mut self) -> Result<()> {
@@ -19,6 +19,7 @@ pub fn draw(f: &mut Frame, app: &App) {
     match app.current_screen {
         Screen::ThemeSelection => draw_theme_selection(f, chunks[0], app),
         Screen::ProjectDetails => draw_project_details(f, chunks[0], app),
+        Screen::SocialLinks => draw_social_links(f, chunks[0], app),
         Screen::GitHubProjects => draw_github_projects(f, chunks[0], app),
         Screen::Confirmation => draw_confirmation(f, chunks[0], app),
         Screen::Progress => draw_progress(f, chunks[0], app),
@@ -83,6 +84,90 @@ fn draw_theme_selection(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     f.render_widget(instructions, instruction_area);
 }
 
+fn draw_social_links(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let block = Block::default()
+        .title(" social links ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let inner = block.inner(area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(5),
+            Constraint::Length(3),
+        ])
+        .split(inner);
+
+    let url_input = Paragraph::new(app.input_fields.current_social_url.as_str()).block(
+        Block::default()
+            .title("Enter a profile URL or email address")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)),
+    );
+
+    f.render_widget(url_input, chunks[0]);
+
+    // links in the order the portfolio shows them, labelled by platform
+    let link_items: Vec<ListItem> = app
+        .input_fields
+        .social_links
+        .iter()
+        .enumerate()
+        .map(|(i, link)| {
+            ListItem::new(vec![Line::from(vec![
+                Span::styled(
+                    format!("{}. {} ", i + 1, link.label),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(&link.url, Style::default().fg(Color::Gray)),
+            ])])
+        })
+        .collect();
+
+    let links_list = List::new(link_items).block(
+        Block::default()
+            .title(format!("Links ({})", app.input_fields.social_links.len()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Gray)),
+    );
+
+    f.render_widget(links_list, chunks[1]);
+
+    let status_msg = if app.input_fields.current_social_url.trim().is_empty() {
+        "Press Enter to continue, or Backspace to remove the last link"
+    } else {
+        "Press Enter to add this link"
+    };
+
+    let status = Paragraph::new(status_msg)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Blue)),
+        )
+        .style(Style::default().fg(Color::White))
+        .alignment(Alignment::Center);
+
+    f.render_widget(status, chunks[2]);
+
+    f.render_widget(block, area);
+
+    let instructions =
+        Paragraph::new("GitHub, LinkedIn, Mastodon, Bluesky, YouTube, itch.io, Dev.to or any site • Enter to add/continue")
+            .style(Style::default().fg(Color::Yellow))
+            .alignment(Alignment::Center);
+
+    let instruction_area = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)].as_ref())
+        .split(f.size())[1];
+
+    f.render_widget(instructions, instruction_area);
+}
+
 fn draw_github_projects(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     let block = Block::default()
         .title(" github projects ")
@@ -242,7 +327,7 @@ fn draw_project_details(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     f.render_widget(block, area);
 
     let instructions =
-        Paragraph::new("Tab/Shift+Tab to navigate • Enter to add GitHub projects • Esc to go back")
+        Paragraph::new("Tab/Shift+Tab to navigate • Enter to add social links • Esc to go back")
             .style(Style::default().fg(Color::Yellow))
             .alignment(Alignment::Center);
 
@@ -269,12 +354,21 @@ fn draw_confirmation(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
         .collect::<Vec<_>>()
         .join("\n");
 
+    let social_links = app
+        .input_fields
+        .social_links
+        .iter()
+        .map(|link| link.label.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+
     let summary = format!(
-        "theme: {}\nproject: {}\nauthor: {}\ntitle: {}\n\ngithub projects ({}):\n{}\n\nready to generate your portfolio?",
+        "theme: {}\nproject: {}\nauthor: {}\ntitle: {}\nlinks: {}\n\ngithub projects ({}):\n{}\n\nready to generate your portfolio?",
         app.selected_theme().display_name,
         app.input_fields.project_name,
         app.input_fields.author_name,
         app.input_fields.title,
+        if social_links.is_empty() { "(none)" } else { &social_links },
         app.input_fields.github_projects.len(),
         if projects_list.is_empty() { "  (none added)" } else { &projects_list }
     );
@@ -1,6 +1,8 @@
 // auto-update functionality for portfolio-arcade
 // checks github releases and prompts user to update when newer version available
 
+use crate::error::ArcadeError;
+use crate::output::Output;
 use anyhow::Result;
 use colored::*;
 use serde::Deserialize;
@@ -126,11 +128,39 @@ fn show_update_instructions(new_version: &str) {
 }
 
 // manual update check (portfolio-arcade update command)
-pub async fn force_update_check() -> Result<()> {
-    println!("{}", "checking for updates...".cyan());
+pub async fn force_update_check(out: &Output) -> Result<()> {
+    out.line("checking for updates...".cyan());
+
+    let latest = match check_for_updates().await {
+        Ok(latest) => latest,
+        // scripts need to know the check didn't happen
+        Err(e) if out.is_json() => {
+            return Err(ArcadeError::GitHub(format!("couldn't check for updates: {}", e)).into())
+        }
+        Err(e) => {
+            eprintln!("{} {}", "couldn't check for updates:".yellow(), e);
+            println!();
+            println!("check manually at:");
+            println!(
+                "  {}",
+                format!("https://github.com/{}/{}/releases", REPO_OWNER, REPO_NAME).bright_blue()
+            );
+            return Ok(());
+        }
+    };
+
+    out.result(serde_json::json!({
+        "command": "update",
+        "current_version": CURRENT_VERSION,
+        "latest_version": latest.as_deref().map(|v| v.trim_start_matches('v')),
+        "update_available": latest.is_some(),
+    }));
+    if out.is_json() {
+        return Ok(());
+    }
 
-    match check_for_updates().await {
-        Ok(Some(new_version)) => {
+    match latest {
+        Some(new_version) => {
             println!();
             println!("{}", "new version available!".bright_green().bold());
             println!("  current: {}", format!("v{}", CURRENT_VERSION).cyan());
@@ -138,19 +168,10 @@ pub async fn force_update_check() -> Result<()> {
             println!();
             show_update_instructions(&new_version);
         }
-        Ok(None) => {
+        None => {
             println!("{}", "you're up to date!".green());
             println!("  version: {}", format!("v{}", CURRENT_VERSION).cyan());
         }
-        Err(e) => {
-            eprintln!("{} {}", "couldn't check for updates:".yellow(), e);
-            println!();
-            println!("check manually at:");
-            println!(
-                "  {}",
-                format!("https://github.com/{}/{}/releases", REPO_OWNER, REPO_NAME).bright_blue()
-            );
-        }
     }
 
     Ok(())
@@ -0,0 +1,35 @@
+// file system helpers shared by the commands
+// sizes and counts for reporting what a command produced
+
+use std::path::Path;
+use walkdir::WalkDir;
+
+// total size in bytes and number of files under a directory
+pub fn dir_size(dir: &Path) -> (u64, usize) {
+    WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .fold((0, 0), |(bytes, files), meta| {
+            (bytes + meta.len(), files + 1)
+        })
+}
+
+// human-readable size like 1.4 MB
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
@@ -1,4 +1,6 @@
 // utility modules for common operations
 // helpers for validation, file system operations, etc
 
+pub mod files;
+pub mod json;
 pub mod validation;
@@ -2,6 +2,7 @@
 // ensures user input is safe and valid
 // these functions are kept for testing and potential future use in the TUI
 
+use crate::models::{PortfolioConfig, Theme};
 use regex::Regex;
 
 // validates project name - alphanumeric, hyphens, underscores only
@@ -90,6 +91,89 @@ pub fn sanitize_filename(input: &str) -> String {
         .to_lowercase()
 }
 
+// validates a full portfolio config, prefixing errors with the failing field
+pub fn validate_config(config: &PortfolioConfig) -> Result<(), String> {
+    let field =
+        |name: &str, result: Result<(), String>| result.map_err(|e| format!("{}: {}", name, e));
+
+    if Theme::find_by_id(&config.theme).is_none() {
+        return Err(format!(
+            "theme: unknown theme '{}' (expected ps3, ps5 or wii)",
+            config.theme
+        ));
+    }
+
+    field(
+        "dev_port",
+        validate_port(&config.dev_port.to_string()).map(|_| ()),
+    )?;
+
+    let user = &config.user;
+    if user.name.trim().is_empty() {
+        return Err("user.name: name cannot be empty".to_string());
+    }
+    if user.title.trim().is_empty() {
+        return Err("user.title: title cannot be empty".to_string());
+    }
+
+    for (i, link) in user.social.iter().enumerate() {
+        let name = format!("user.social[{}].url", i);
+        if link.url.trim().is_empty() {
+            return Err(format!("{}: {} link has no url", name, link.label));
+        }
+        match link.email() {
+            Some(email) => field(&name, validate_email(email))?,
+            None => field(&name, validate_url(&link.url))?,
+        }
+    }
+
+    for (i, project) in config.projects.iter().enumerate() {
+        if project.id.trim().is_empty() {
+            return Err(format!("projects[{}].id: project id cannot be empty", i));
+        }
+        if project.title.trim().is_empty() {
+            return Err(format!(
+                "projects[{}].title: project title cannot be empty",
+                i
+            ));
+        }
+        for (name, url) in [
+            ("github", &project.links.github),
+            ("live", &project.links.live),
+            ("demo", &project.links.demo),
+        ] {
+            field(
+                &format!("projects[{}].links.{}", i, name),
+                validate_url(url.as_deref().unwrap_or("")),
+            )?;
+        }
+    }
+
+    for (i, post) in config.posts.iter().enumerate() {
+        if post.title.trim().is_empty() {
+            return Err(format!("posts[{}].title: post title cannot be empty", i));
+        }
+        // the slug names the markdown file under content/posts
+        let slug = post.file_slug();
+        if slug.is_empty()
+            || !slug
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(format!(
+                "posts[{}].slug: '{}' can only contain letters, numbers, hyphens and underscores",
+                i, slug
+            ));
+        }
+        field(
+            &format!("posts[{}].url", i),
+            validate_url(post.url.as_deref().unwrap_or("")),
+        )?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
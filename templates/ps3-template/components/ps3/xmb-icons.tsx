@@ -5,6 +5,7 @@ import {
   Cpu,
   Gamepad2,
   Globe,
+  GraduationCap,
   Settings,
   User,
   Users,
@@ -28,6 +29,7 @@ const iconMap: Record<string, React.ComponentType<LucideProps>> = {
   briefcase: Briefcase,
   code: Code,
   globe: Globe,
+  "graduation-cap": GraduationCap,
   monitor: Monitor,
   download: Download,
   mail: Mail,
@@ -2,21 +2,30 @@
 // This file should only be imported in Server Components or API routes
 import type { XMBCategory, XMBItem, UserProfile } from "./xmb-data"
 
+// a profile link, platform is one of github, linkedin, email, website, twitter,
+// mastodon, bluesky, youtube, itchio, devto or custom; email urls use mailto:
+export interface SocialLink {
+  platform: string
+  url: string
+  label: string
+}
+
 export interface PortfolioData {
   user: {
     name: string
     title: string
     bio: string
     avatar: string
-    social: {
-      github?: string
-      linkedin?: string
-      email?: string
-      website?: string
-      twitter?: string
-    }
+    social: SocialLink[]
   }
   projects: XMBItem[]
+  // skills arrive as ready-made XMB items with a progress percentage
+  skills?: XMBItem[]
+  // experience and education arrive as XMB items, highlights as bullet lines
+  experience?: XMBItem[]
+  education?: XMBItem[]
+  // posts arrive as XMB items carrying their markdown body
+  posts?: (XMBItem & { body?: string })[]
   theme: string
 }
 
@@ -37,6 +46,8 @@ export async function loadPortfolioCategories(): Promise<XMBCategory[]> {
 
 // Transforms portfolio data into PS3 XMB category structure
 function buildXMBCategories(data: PortfolioData): XMBCategory[] {
+  const careerItems = [...(data.experience || []), ...(data.education || [])]
+
   return [
     {
       id: "users",
@@ -79,8 +90,29 @@ function buildXMBCategories(data: PortfolioData): XMBCategory[] {
       id: "tech",
       label: "Tech Stack",
       icon: "cpu",
-      items: buildTechStackItems(data.projects),
+      items: data.skills?.length ? data.skills : buildTechStackItems(data.projects),
     },
+    // only shown when the portfolio lists any experience or education
+    ...(careerItems.length
+      ? [
+          {
+            id: "career",
+            label: "Career",
+            icon: "briefcase",
+            items: careerItems,
+          },
+        ]
+      : []),
+    ...(data.posts?.length
+      ? [
+          {
+            id: "posts",
+            label: "Posts",
+            icon: "file",
+            items: data.posts,
+          },
+        ]
+      : []),
     {
       id: "about",
       label: "About",
@@ -252,24 +284,15 @@ function buildTechStackItems(projects: XMBItem[]): XMBItem[] {
   return items
 }
 
-// Builds contact description from social links
-function buildContactDescription(social: PortfolioData["user"]["social"]): string {
-  const parts = []
-  if (social.email) parts.push(`Email: ${social.email}`)
-  if (social.github) parts.push(`GitHub: ${social.github}`)
-  if (social.linkedin) parts.push(`LinkedIn: ${social.linkedin}`)
+// Builds contact description from social links, in the order they're listed
+function buildContactDescription(social: SocialLink[] = []): string {
+  const parts = social.map((link) => `${link.label}: ${link.url.replace(/^mailto:/, "")}`)
   return parts.join("\n") || "Get in touch to collaborate!"
 }
 
 // Builds contact links array
-function buildContactLinks(social: PortfolioData["user"]["social"]): { label: string; url: string }[] {
-  const links = []
-  if (social.email) links.push({ label: "Email", url: `mailto:${social.email}` })
-  if (social.github) links.push({ label: "GitHub", url: social.github })
-  if (social.linkedin) links.push({ label: "LinkedIn", url: social.linkedin })
-  if (social.twitter) links.push({ label: "Twitter", url: social.twitter })
-  if (social.website) links.push({ label: "Website", url: social.website })
-  return links
+function buildContactLinks(social: SocialLink[] = []): { label: string; url: string }[] {
+  return social.map((link) => ({ label: link.label, url: link.url }))
 }
 
 // Returns default categories if JSON doesn't exist
@@ -11,7 +11,17 @@ export interface XMBItem {
   tags?: string[]
   links?: { label: string; url: string }[]
   image?: string
+  screenshots?: string[]
   profilePriority?: UserProfile[]
+  progress?: number
+  // github repo metadata, only on imported projects
+  stars?: number
+  forks?: number
+  topics?: string[]
+  language?: string | null
+  pushedAt?: string | null
+  license?: string | null
+  archived?: boolean
 }
 
 export interface XMBCategory {
@@ -1,21 +1,56 @@
 // Loads portfolio data from data/portfolio.json with fallback to defaults
 import type { UserProfile, Project } from "./projects"
 
+// shown on the profile card, level is 0-100
+export interface Skill {
+  name: string
+  category: string
+  level: number
+}
+
+// a job or a course of study, endDate is empty while it's ongoing
+export interface TimelineEntry {
+  organization: string
+  role: string
+  period: string
+  startDate: string
+  endDate: string
+  highlights: string[]
+  priority: { recruiter: number; engineer: number; stranger: number }
+}
+
+// an article from content/posts, url is set when it's published elsewhere
+export interface Post {
+  id: string
+  title: string
+  date: string
+  summary: string
+  url?: string | null
+  body: string
+  tags: string[]
+}
+
+// a profile link, platform is one of github, linkedin, email, website, twitter,
+// mastodon, bluesky, youtube, itchio, devto or custom; email urls use mailto:
+export interface SocialLink {
+  platform: string
+  url: string
+  label: string
+}
+
 export interface PortfolioData {
   user: {
     name: string
     title: string
     bio: string
     avatar: string
-    social: {
-      github?: string
-      linkedin?: string
-      email?: string
-      website?: string
-      twitter?: string
-    }
+    social: SocialLink[]
   }
   projects: Project[]
+  skills?: Skill[]
+  experience?: TimelineEntry[]
+  education?: TimelineEntry[]
+  posts?: Post[]
   theme: string
 }
 
@@ -42,11 +77,11 @@ function getDefaultData(): PortfolioData {
       title: "Software Developer",
       bio: "A passionate developer creating amazing digital experiences",
       avatar: "/images/avatar.jpg",
-      social: {
-        github: "https://github.com/username",
-        linkedin: "https://linkedin.com/in/username",
-        email: "hello@example.com",
-      },
+      social: [
+        { platform: "github", url: "https://github.com/username", label: "GitHub" },
+        { platform: "linkedin", url: "https://linkedin.com/in/username", label: "LinkedIn" },
+        { platform: "email", url: "mailto:hello@example.com", label: "Email" },
+      ],
     },
     projects: [],
     theme: "ps5",
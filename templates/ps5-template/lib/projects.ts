@@ -8,6 +8,7 @@ export interface Project {
   subtitle: string
   description: string
   fullDescription: string
+  date?: string
   techStack: string[]
   achievements: number
   totalAchievements: number
@@ -18,6 +19,14 @@ export interface Project {
   githubUrl?: string
   demoVideo?: string
   screenshots?: string[]
+  // github repo metadata, only on imported projects
+  stars?: number
+  forks?: number
+  topics?: string[]
+  language?: string | null
+  pushedAt?: string | null
+  license?: string | null
+  archived?: boolean
   priority: {
     recruiter: number
     engineer: number
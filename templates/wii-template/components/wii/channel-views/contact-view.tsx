@@ -2,12 +2,50 @@
 
 import React from "react"
 
-import { useState } from "react"
-import { Send, Download, Github, Linkedin, Twitter, CheckCircle } from "lucide-react"
+import { useState, useEffect } from "react"
+import {
+  Send,
+  Download,
+  Github,
+  Linkedin,
+  Twitter,
+  Mail,
+  Globe,
+  AtSign,
+  Cloud,
+  Youtube,
+  Gamepad2,
+  FileText,
+  Link,
+  CheckCircle,
+} from "lucide-react"
+import { loadPortfolioData, type SocialLink } from "@/lib/load-portfolio-data"
+
+// one icon per platform in portfolio.json, custom links get a plain link icon
+const platformIcons: Record<string, React.ComponentType<{ size?: number; className?: string }>> = {
+  github: Github,
+  linkedin: Linkedin,
+  email: Mail,
+  website: Globe,
+  twitter: Twitter,
+  mastodon: AtSign,
+  bluesky: Cloud,
+  youtube: Youtube,
+  itchio: Gamepad2,
+  devto: FileText,
+}
 
 export function ContactView() {
   const [sent, setSent] = useState(false)
   const [formData, setFormData] = useState({ name: "", email: "", message: "" })
+  const [social, setSocial] = useState<SocialLink[]>([])
+
+  // Load the social links from portfolio.json on mount, in the order they're listed
+  useEffect(() => {
+    loadPortfolioData().then((data) => {
+      setSocial(data.user.social ?? [])
+    })
+  }, [])
 
   const handleSubmit = (e: React.FormEvent) => {
     e.preventDefault()
@@ -74,13 +112,15 @@ export function ContactView() {
         </h3>
         <div className="grid grid-cols-2 md:grid-cols-4 gap-3">
           {[
-            { icon: Github, label: "GitHub", href: "#" },
-            { icon: Linkedin, label: "LinkedIn", href: "#" },
-            { icon: Twitter, label: "Twitter", href: "#" },
+            ...social.map((link) => ({
+              icon: platformIcons[link.platform] ?? Link,
+              label: link.label,
+              href: link.url,
+            })),
             { icon: Download, label: "Resume", href: "#" },
           ].map(({ icon: Icon, label, href }) => (
             <a
-              key={label}
+              key={`${label}-${href}`}
               href={href}
               target="_blank"
               rel="noopener noreferrer"
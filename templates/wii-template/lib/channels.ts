@@ -160,8 +160,14 @@ export const channels: Channel[] = [
  * @property techStack - Array of technology names (e.g., ["React", "TypeScript"])
  * @property liveUrl - URL to deployed project (optional)
  * @property githubUrl - URL to GitHub repo (optional)
+ * @property demoUrl - URL to a demo video (optional)
+ * @property date - When the project was made, e.g. "2024-01" (optional)
+ * @property thumbnail - Image URL for the project (optional)
+ * @property screenshots - Image URLs of the project (optional)
  * @property category - Array of channel IDs: "web-apps", "open-source", "creative-lab", "archive"
  * @property featured - If true, shows in recruiter/featured view (optional)
+ * @property stars, forks, topics, language, pushedAt, license, archived - GitHub
+ *           repo metadata, only set on imported projects
  */
 export type Project = {
   id: string
@@ -171,8 +177,19 @@ export type Project = {
   techStack: string[]
   liveUrl?: string
   githubUrl?: string
+  demoUrl?: string | null
+  date?: string
+  thumbnail?: string
+  screenshots?: string[]
   category: string[]
   featured?: boolean
+  stars?: number
+  forks?: number
+  topics?: string[]
+  language?: string | null
+  pushedAt?: string | null
+  license?: string | null
+  archived?: boolean
 }
 
 /**
@@ -1,21 +1,60 @@
 // Loads portfolio data from data/portfolio.json with fallback to defaults
 import type { Project } from "./channels"
 
+// shown on the skills channel, stars run from 1 to 5
+// channel is the category as a slug
+export interface Skill {
+  name: string
+  channel: string
+  category?: string
+  stars: number
+  proficiency: number
+}
+
+// a job or a course of study, endDate is empty while it's ongoing
+export interface TimelineEntry {
+  organization: string
+  role: string
+  period: string
+  startDate: string
+  endDate: string
+  highlights: string[]
+  category: string[]
+}
+
+// an article from content/posts, url is set when it's published elsewhere
+export interface Post {
+  id: string
+  title: string
+  date: string
+  tagline: string
+  url?: string | null
+  body: string
+  tags: string[]
+  category: string[]
+}
+
+// a profile link, platform is one of github, linkedin, email, website, twitter,
+// mastodon, bluesky, youtube, itchio, devto or custom; email urls use mailto:
+export interface SocialLink {
+  platform: string
+  url: string
+  label: string
+}
+
 export interface PortfolioData {
   user: {
     name: string
     title: string
     bio: string
     avatar: string
-    social: {
-      github?: string
-      linkedin?: string
-      email?: string
-      website?: string
-      twitter?: string
-    }
+    social: SocialLink[]
   }
   projects: Project[]
+  skills?: Skill[]
+  experience?: TimelineEntry[]
+  education?: TimelineEntry[]
+  posts?: Post[]
   theme: string
 }
 
@@ -42,11 +81,11 @@ function getDefaultData(): PortfolioData {
       title: "Software Developer",
       bio: "A passionate developer creating amazing digital experiences",
       avatar: "/images/avatar.jpg",
-      social: {
-        github: "https://github.com/username",
-        linkedin: "https://linkedin.com/in/username",
-        email: "hello@example.com",
-      },
+      social: [
+        { platform: "github", url: "https://github.com/username", label: "GitHub" },
+        { platform: "linkedin", url: "https://linkedin.com/in/username", label: "LinkedIn" },
+        { platform: "email", url: "mailto:hello@example.com", label: "Email" },
+      ],
     },
     projects: [],
     theme: "wii",
@@ -1,4 +1,13 @@
+// `portfolio-arcade build` sets PORTFOLIO_ARCADE_EXPORT to get a static site in out/
+const staticExport = process.env.PORTFOLIO_ARCADE_EXPORT === '1'
+
 /** @type {import('next').NextConfig} */
-const nextConfig = {}
+const nextConfig = staticExport
+  ? {
+      output: 'export',
+      trailingSlash: true,
+      images: { unoptimized: true },
+    }
+  : {}
 
 export default nextConfig
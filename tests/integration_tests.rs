@@ -1,9 +1,31 @@
 // integration tests for portfolio arcade
 // validates core functionality
 
-use portfolio_arcade::models::{PortfolioConfig, Theme};
+use portfolio_arcade::models::{PortfolioConfig, RepoMetadata, Theme};
 use portfolio_arcade::utils::validation::{validate_port, validate_project_name};
 
+// a defaults file that never exists, so the developer's own
+// ~/.config/portfolio-arcade/config.toml can't leak into the tests
+const NO_USER_DEFAULTS: &str = concat!(env!("CARGO_TARGET_TMPDIR"), "/no-user-defaults.toml");
+
+// the cli binary with user defaults switched off
+fn arcade() -> std::process::Command {
+    let mut command = std::process::Command::new(env!("CARGO_BIN_EXE_portfolio-arcade"));
+    command.env("PORTFOLIO_ARCADE_CONFIG", NO_USER_DEFAULTS);
+    command
+}
+
+// runs the cli in dir and waits for it
+fn run(dir: &std::path::Path, args: &[&str]) -> std::process::Output {
+    arcade().args(args).current_dir(dir).output().unwrap()
+}
+
+// the same for tests that call into the library directly
+fn no_user_defaults() {
+    static ONCE: std::sync::Once = std::sync::Once::new();
+    ONCE.call_once(|| std::env::set_var("PORTFOLIO_ARCADE_CONFIG", NO_USER_DEFAULTS));
+}
+
 #[test]
 fn test_theme_availability() {
     let themes = Theme::available_themes();
@@ -78,3 +100,1834 @@ fn test_project_data_adaptation() {
     let json = adapted.unwrap();
     assert!(json.is_array(), "adapted data should be an array");
 }
+
+#[test]
+fn test_partial_config_file_uses_defaults() {
+    use portfolio_arcade::commands::init::load_config_file;
+    no_user_defaults();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("portfolio.json");
+    std::fs::write(
+        &path,
+        r#"{ "theme": "wii", "cms": "none", "user": { "name": "ada" } }"#,
+    )
+    .unwrap();
+
+    let config = load_config_file(&path).unwrap();
+    assert_eq!(config.theme, "wii");
+    assert_eq!(config.user.name, "ada");
+    assert_eq!(config.user.title, PortfolioConfig::default().user.title);
+    assert_eq!(config.dev_port, 3000);
+    assert!(!config.projects.is_empty());
+}
+
+#[test]
+fn test_config_validation_names_failing_field() {
+    use portfolio_arcade::utils::validation::validate_config;
+
+    assert!(validate_config(&PortfolioConfig::default()).is_ok());
+
+    let config = PortfolioConfig {
+        dev_port: 80,
+        ..Default::default()
+    };
+    assert!(validate_config(&config)
+        .unwrap_err()
+        .starts_with("dev_port:"));
+
+    let mut config = PortfolioConfig::default();
+    config
+        .user
+        .social
+        .push(portfolio_arcade::models::SocialLink::new(
+            portfolio_arcade::models::SocialPlatform::Email,
+            "not-an-email",
+        ));
+    assert!(validate_config(&config)
+        .unwrap_err()
+        .starts_with("user.social[3].url:"));
+
+    let config = PortfolioConfig {
+        theme: "dreamcast".to_string(),
+        ..Default::default()
+    };
+    assert!(validate_config(&config).unwrap_err().starts_with("theme:"));
+}
+
+#[test]
+fn test_headless_init_generates_without_tui() {
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("config.json");
+    std::fs::write(&config_path, r#"{ "user": { "name": "grace" } }"#).unwrap();
+
+    let status = arcade()
+        .args(["init", "headless-site", "--theme", "ps3", "--cms", "none"])
+        .arg("--config")
+        .arg(&config_path)
+        .current_dir(dir.path())
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let data = std::fs::read_to_string(dir.path().join("headless-site/public/data/portfolio.json"))
+        .unwrap();
+    let data: serde_json::Value = serde_json::from_str(&data).unwrap();
+    assert_eq!(data["theme"], "ps3");
+    assert_eq!(data["user"]["name"], "grace");
+}
+
+#[test]
+fn test_headless_init_rejects_invalid_config() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = run(dir.path(), &["init", "bad-port", "--port", "80"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("dev_port"));
+    assert!(!dir.path().join("bad-port").exists());
+    assert_eq!(output.status.code(), Some(3));
+}
+
+#[test]
+fn test_json_output_and_exit_codes() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = run(dir.path(), &["--json", "init", "site", "--theme", "wii"]);
+    assert!(output.status.success());
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["ok"], true);
+    assert_eq!(result["command"], "init");
+    assert_eq!(result["theme"], "wii");
+    assert_eq!(result["project_count"], 1);
+    assert!(result["path"].as_str().unwrap().ends_with("site"));
+
+    // the target already exists now
+    let output = run(dir.path(), &["init", "site", "--theme", "wii", "--json"]);
+    assert_eq!(output.status.code(), Some(5));
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["ok"], false);
+    assert_eq!(result["exit_code"], 5);
+
+    let output = run(dir.path(), &["remove-project", "project-1", "--path", "."]);
+    assert_eq!(output.status.code(), Some(7));
+
+    // unparseable or wrongly typed config files are invalid config too
+    std::fs::write(dir.path().join("broken.json"), "{ \"user\": ").unwrap();
+    std::fs::write(dir.path().join("typed.json"), r#"{ "dev_port": "soon" }"#).unwrap();
+    for file in ["broken.json", "typed.json"] {
+        let output = run(dir.path(), &["init", "other", "--config", file]);
+        assert_eq!(output.status.code(), Some(3), "{}", file);
+        assert!(String::from_utf8_lossy(&output.stderr).contains(file));
+    }
+}
+
+#[test]
+fn test_adapted_projects_can_be_recovered() {
+    use portfolio_arcade::models::Skill;
+
+    let mut config = PortfolioConfig::default();
+    config.projects[0].links.demo = Some("https://youtu.be/demo".to_string());
+    config.projects[0].repo = Some(RepoMetadata {
+        stars: 12,
+        language: Some("TypeScript".to_string()),
+        ..Default::default()
+    });
+    config.projects[0]
+        .extra
+        .insert("difficulty".to_string(), serde_json::json!("hard"));
+    config.skills = vec![Skill {
+        name: "c++".to_string(),
+        category: "Front-End".to_string(),
+        proficiency: 70,
+    }];
+    let original = &config.projects[0];
+
+    for theme in Theme::available_themes() {
+        let adapted = theme.adapt_projects(&config.projects).unwrap();
+        let recovered = theme.recover_projects(&adapted).unwrap();
+
+        assert_eq!(recovered.len(), 1, "{} lost projects", theme.id);
+        let project = &recovered[0];
+        assert_eq!(project.id, original.id, "{} lost the id", theme.id);
+        assert_eq!(project.title, original.title, "{} lost the title", theme.id);
+        assert_eq!(project.tech_stack, original.tech_stack);
+        assert_eq!(project.links.github, original.links.github);
+        assert_eq!(project.links.live, original.links.live);
+        assert_eq!(project.links.demo, original.links.demo, "{}", theme.id);
+        assert_eq!(project.featured, original.featured);
+        assert_eq!(project.full_description, original.full_description);
+        assert_eq!(project.date, original.date, "{} lost the date", theme.id);
+        assert_eq!(project.thumbnail, original.thumbnail, "{}", theme.id);
+        assert_eq!(project.screenshots, original.screenshots, "{}", theme.id);
+        assert_eq!(project.repo, original.repo, "{}", theme.id);
+        // computed adapter fields must not leak back into extra
+        assert_eq!(project.extra, original.extra, "{}", theme.id);
+
+        let skills = theme.recover_skills(&theme.adapt_skills(&config.skills));
+        assert_eq!(skills, config.skills, "{} changed the skills", theme.id);
+    }
+}
+
+#[test]
+fn test_generated_project_config_round_trip() {
+    use portfolio_arcade::generator::GeneratedProject;
+
+    let dir = tempfile::tempdir().unwrap();
+    let data_dir = dir.path().join("public/data");
+    std::fs::create_dir_all(&data_dir).unwrap();
+
+    let mut config = PortfolioConfig {
+        theme: "wii".to_string(),
+        dev_port: 4000,
+        ..Default::default()
+    };
+    config.projects[0].repo = Some(RepoMetadata {
+        stars: 42,
+        ..Default::default()
+    });
+
+    let theme = Theme::find_by_id("wii").unwrap();
+    let mut data = theme.portfolio_data(&config).unwrap();
+    data["custom"] = serde_json::json!("kept");
+    std::fs::write(
+        data_dir.join("portfolio.json"),
+        serde_json::to_string(&data).unwrap(),
+    )
+    .unwrap();
+
+    let mut project = GeneratedProject::open(dir.path()).unwrap();
+    let mut loaded = project.load_config().unwrap();
+    assert_eq!(loaded.dev_port, 4000);
+    assert_eq!(loaded.projects[0].stars(), 42);
+
+    loaded.projects[0].repo.as_mut().unwrap().stars = 43;
+    project.save_config(&loaded).unwrap();
+
+    let reopened = GeneratedProject::open(dir.path()).unwrap();
+    assert_eq!(reopened.data["custom"], "kept");
+    assert_eq!(reopened.data["projects"][0]["stars"], 43);
+}
+
+#[test]
+fn test_add_and_remove_manual_project() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(run(dir.path(), &["init", "site", "--theme", "ps5"])
+        .status
+        .success());
+    let added = run(
+        dir.path(),
+        &[
+            "add-project",
+            "--path",
+            "site",
+            "--title",
+            "retro synth",
+            "--description",
+            "a web audio synthesizer",
+            "--tech",
+            "rust,wasm",
+        ],
+    );
+    assert!(added.status.success());
+
+    let read_projects = || {
+        let data =
+            std::fs::read_to_string(dir.path().join("site/public/data/portfolio.json")).unwrap();
+        let data: serde_json::Value = serde_json::from_str(&data).unwrap();
+        data["projects"].as_array().unwrap().clone()
+    };
+
+    let projects = read_projects();
+    assert_eq!(projects.len(), 2);
+    assert_eq!(projects[1]["id"], "project-2");
+    assert_eq!(projects[1]["title"], "retro synth");
+    assert_eq!(
+        projects[1]["techStack"],
+        serde_json::json!(["rust", "wasm"])
+    );
+
+    assert!(
+        !run(dir.path(), &["remove-project", "missing", "--path", "site"])
+            .status
+            .success()
+    );
+    assert!(run(
+        dir.path(),
+        &["remove-project", "project-2", "--path", "site"]
+    )
+    .status
+    .success());
+    assert_eq!(read_projects().len(), 1);
+}
+
+#[test]
+fn test_switch_theme_keeps_content() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(run(dir.path(), &["init", "site", "--theme", "ps3"])
+        .status
+        .success());
+
+    // hand edits to portfolio.json should survive the switch
+    let data_path = dir.path().join("site/public/data/portfolio.json");
+    let mut data: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&data_path).unwrap()).unwrap();
+    data["projects"][0]["label"] = serde_json::json!("edited title");
+    data["custom"] = serde_json::json!("kept");
+    std::fs::write(&data_path, serde_json::to_string(&data).unwrap()).unwrap();
+    std::fs::write(dir.path().join("site/public/projects/shot.png"), b"png").unwrap();
+    std::fs::write(
+        dir.path().join("site/content/projects/sample-project.md"),
+        "# written by hand\n",
+    )
+    .unwrap();
+
+    let output = run(dir.path(), &["switch-theme", "wii", "--path", "site"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let switched = dir.path().join("site-wii");
+    let data: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(switched.join("public/data/portfolio.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(data["theme"], "wii");
+    assert_eq!(data["projects"][0]["title"], "edited title");
+    assert_eq!(data["custom"], "kept");
+    assert!(switched.join("public/projects/shot.png").exists());
+    assert!(switched.join("content/projects/sample-project.md").exists());
+    // carried files don't count as edits made after the switch
+    let manifest = portfolio_arcade::generator::manifest::Manifest::load(&switched)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        manifest.edited_files(&switched),
+        Vec::<std::path::PathBuf>::new()
+    );
+    assert!(manifest.files.contains_key("public/projects/shot.png"));
+
+    // the original stays untouched
+    assert!(dir.path().join("site/public/data/portfolio.json").exists());
+    assert!(!run(dir.path(), &["switch-theme", "ps3", "--path", "site"])
+        .status
+        .success());
+}
+
+#[tokio::test]
+async fn test_doctor_checks() {
+    use portfolio_arcade::commands::doctor::{check_port, check_templates, CheckStatus};
+
+    // tests run from the crate root, so the bundled templates resolve
+    let checks = check_templates();
+    assert_eq!(checks.len(), 4);
+    assert!(checks.iter().all(|c| c.status == CheckStatus::Pass));
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let check = check_port(port).await;
+    assert_eq!(check.status, CheckStatus::Warn);
+    assert!(check.hint.is_some());
+
+    drop(listener);
+    assert_eq!(check_port(port).await.status, CheckStatus::Pass);
+}
+
+#[test]
+fn test_doctor_checks_the_portfolio_dev_port() {
+    use portfolio_arcade::commands::doctor::default_port;
+
+    let dir = tempfile::tempdir().unwrap();
+    let output = run(
+        dir.path(),
+        &["init", "site", "--theme", "ps5", "--port", "5173"],
+    );
+    assert!(output.status.success());
+
+    assert_eq!(default_port(&dir.path().join("site")), 5173);
+    assert_eq!(default_port(dir.path()), 3000);
+}
+
+#[test]
+fn test_json_resume_import() {
+    use portfolio_arcade::models::{JsonResume, SocialPlatform};
+
+    let resume: JsonResume = serde_json::from_str(
+        r#"{
+            "basics": {
+                "name": "Ada Lovelace",
+                "email": "ada@example.com",
+                "summary": "first programmer",
+                "profiles": [
+                    { "network": "GitHub", "username": "ada" },
+                    { "network": "LinkedIn", "url": "https://linkedin.com/in/ada" }
+                ]
+            },
+            "work": [{ "name": "Analytical Engines", "position": "Engineer" }],
+            "projects": [{
+                "name": "Bernoulli numbers",
+                "description": "note g",
+                "highlights": ["first published algorithm"],
+                "keywords": ["math"],
+                "startDate": "1843-01-01",
+                "url": "https://github.com/ada/note-g"
+            }],
+            "awards": [{ "title": "Lovelace medal" }],
+            "interests": [],
+            "meta": { "version": "v1.0.0" }
+        }"#,
+    )
+    .unwrap();
+    // sections the portfolio can't hold are reported rather than silently dropped
+    assert_eq!(resume.skipped_sections(), vec!["awards"]);
+
+    let config = resume.into_config(PortfolioConfig::default());
+    assert_eq!(config.user.name, "Ada Lovelace");
+    // no label in basics, so the latest position becomes the title
+    assert_eq!(config.user.title, "Engineer");
+    assert_eq!(config.user.bio, "first programmer");
+    let social = &config.user.social;
+    assert_eq!(
+        social.find(SocialPlatform::Github).map(|l| l.url.as_str()),
+        Some("https://github.com/ada")
+    );
+    assert_eq!(social.email(), Some("ada@example.com"));
+    assert!(social.find(SocialPlatform::Twitter).is_none());
+
+    assert_eq!(config.projects.len(), 1);
+    let project = &config.projects[0];
+    assert_eq!(project.title, "Bernoulli numbers");
+    assert_eq!(project.tech_stack, vec!["math"]);
+    assert_eq!(project.date, "1843-01-01");
+    assert!(project
+        .full_description
+        .contains("- first published algorithm"));
+    assert_eq!(
+        project.links.github.as_deref(),
+        Some("https://github.com/ada/note-g")
+    );
+}
+
+#[test]
+fn test_json_resume_export_round_trip() {
+    use portfolio_arcade::models::{JsonResume, SocialPlatform};
+
+    let mut config = PortfolioConfig::default();
+    config.user.name = "grace".to_string();
+    config.projects[0].full_description = "compiler work\n\n- first linker".to_string();
+    config.projects[0].date = "someday".to_string();
+
+    let resume = JsonResume::from_config(&config);
+    let json = serde_json::to_value(&resume).unwrap();
+    assert!(json["$schema"].as_str().unwrap().contains("resume-schema"));
+    assert_eq!(json["basics"]["name"], "grace");
+    assert_eq!(json["basics"]["profiles"][0]["network"], "GitHub");
+    assert_eq!(json["basics"]["profiles"][0]["username"], "username");
+
+    let project = &json["projects"][0];
+    assert_eq!(project["keywords"][1], "next.js");
+    assert_eq!(project["highlights"][0], "first linker");
+    assert_eq!(project["url"], "https://yourname.dev");
+    // invalid dates are left out rather than failing schema validation
+    assert!(project.get("startDate").is_none());
+
+    let back = resume.into_config(PortfolioConfig::default());
+    assert_eq!(back.user.name, "grace");
+    assert_eq!(back.projects[0].tech_stack, config.projects[0].tech_stack);
+    assert_eq!(
+        back.user.social.find(SocialPlatform::Github),
+        config.user.social.find(SocialPlatform::Github)
+    );
+}
+
+#[test]
+fn test_build_output_size_reporting() {
+    use portfolio_arcade::utils::files::{dir_size, format_size};
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("out/_next")).unwrap();
+    std::fs::write(dir.path().join("out/index.html"), vec![b'a'; 1000]).unwrap();
+    std::fs::write(dir.path().join("out/_next/app.js"), vec![b'b'; 2000]).unwrap();
+
+    assert_eq!(dir_size(&dir.path().join("out")), (3000, 2));
+    assert_eq!(format_size(512), "512 B");
+    assert_eq!(format_size(3000), "2.9 KB");
+    assert_eq!(format_size(5 * 1024 * 1024), "5.0 MB");
+}
+
+#[test]
+fn test_generated_portfolio_supports_static_export() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(run(dir.path(), &["init", "site", "--theme", "ps3"])
+        .status
+        .success());
+    let next_config = std::fs::read_to_string(dir.path().join("site/next.config.mjs")).unwrap();
+    assert!(next_config.contains("PORTFOLIO_ARCADE_EXPORT"));
+    assert!(next_config.contains("output: 'export'"));
+
+    // build refuses anything that isn't a generated portfolio before touching node
+    assert_eq!(run(dir.path(), &["build", "."]).status.code(), Some(7));
+}
+
+#[tokio::test]
+async fn test_serve_static_export() {
+    use portfolio_arcade::commands::serve::{mime_type, serve};
+    use portfolio_arcade::output::Output;
+
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().join("out");
+    std::fs::create_dir_all(root.join("about")).unwrap();
+    std::fs::create_dir_all(root.join("_next")).unwrap();
+    std::fs::write(root.join("index.html"), "<h1>home</h1>").unwrap();
+    std::fs::write(root.join("about/index.html"), "<h1>about</h1>").unwrap();
+    std::fs::write(root.join("contact.html"), "<h1>contact</h1>").unwrap();
+    std::fs::write(root.join("_next/app.js"), "console.log(1)").unwrap();
+    std::fs::write(root.join("404.html"), "<h1>lost</h1>").unwrap();
+    std::fs::write(dir.path().join("secret.txt"), "nope").unwrap();
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(serve(listener, root, Output::new(true)));
+
+    let get = |path: &str| {
+        let url = format!("{}{}", base, path);
+        async move { reqwest::get(url).await.unwrap() }
+    };
+
+    let response = get("/").await;
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/html; charset=utf-8"
+    );
+    assert_eq!(response.text().await.unwrap(), "<h1>home</h1>");
+
+    assert_eq!(get("/about").await.text().await.unwrap(), "<h1>about</h1>");
+    assert_eq!(get("/about/").await.text().await.unwrap(), "<h1>about</h1>");
+    assert_eq!(
+        get("/contact?ref=x").await.text().await.unwrap(),
+        "<h1>contact</h1>"
+    );
+
+    let response = get("/_next/app.js").await;
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/javascript; charset=utf-8"
+    );
+
+    let response = get("/missing").await;
+    assert_eq!(response.status(), 404);
+    assert_eq!(response.text().await.unwrap(), "<h1>lost</h1>");
+
+    assert_eq!(get("/%2e%2e/secret.txt").await.status(), 404);
+    assert_eq!(mime_type(std::path::Path::new("logo.SVG")), "image/svg+xml");
+}
+
+#[test]
+fn test_deploy_to_bare_repository() {
+    let dir = tempfile::tempdir().unwrap();
+    let git = |args: &[&str]| {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).to_string()
+    };
+
+    git(&["init", "--bare", "--quiet", "remote.git"]);
+    assert!(run(dir.path(), &["init", "site", "--theme", "ps5"])
+        .status
+        .success());
+
+    // nothing to deploy before a build
+    assert!(!run(
+        dir.path(),
+        &["deploy", "site", "--git", "--remote", "../remote.git"]
+    )
+    .status
+    .success());
+
+    std::fs::create_dir_all(dir.path().join("site/out/_next")).unwrap();
+    std::fs::write(dir.path().join("site/out/index.html"), "<h1>hi</h1>").unwrap();
+    std::fs::write(dir.path().join("site/out/_next/app.js"), "1").unwrap();
+
+    let remote = dir.path().join("remote.git");
+    let output = run(
+        dir.path(),
+        &[
+            "--json",
+            "deploy",
+            "site",
+            "--git",
+            "--remote",
+            remote.to_str().unwrap(),
+            "--cname",
+            "me.example.com",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["branch"], "gh-pages");
+
+    let files = git(&[
+        "--git-dir",
+        "remote.git",
+        "ls-tree",
+        "-r",
+        "--name-only",
+        "gh-pages",
+    ]);
+    let files: Vec<&str> = files.lines().collect();
+    assert_eq!(files, [".nojekyll", "CNAME", "_next/app.js", "index.html"]);
+    assert_eq!(
+        git(&["--git-dir", "remote.git", "show", "gh-pages:CNAME"]),
+        "me.example.com\n"
+    );
+
+    // redeploying replaces the branch with a single fresh commit
+    std::fs::write(dir.path().join("site/out/index.html"), "<h1>v2</h1>").unwrap();
+    let output = run(
+        dir.path(),
+        &[
+            "deploy",
+            "site",
+            "--git",
+            "--remote",
+            "../remote.git",
+            "--branch",
+            "pages",
+        ],
+    );
+    assert!(output.status.success());
+    assert_eq!(
+        git(&["--git-dir", "remote.git", "rev-list", "--count", "pages"]).trim(),
+        "1"
+    );
+
+    // a remote name wins over a same-named directory in the cwd, and its
+    // relative url is resolved from the portfolio's repository
+    std::fs::create_dir_all(dir.path().join("origin")).unwrap();
+    git(&["init", "--quiet", "site"]);
+    git(&["-C", "site", "remote", "add", "origin", "../remote.git"]);
+    let output = run(dir.path(), &["deploy", "site", "--git"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        git(&["--git-dir", "remote.git", "show", "gh-pages:index.html"]),
+        "<h1>v2</h1>"
+    );
+
+    // a branch with history of its own is only replaced with --force
+    git(&[
+        "-C",
+        "site",
+        "-c",
+        "user.name=test",
+        "-c",
+        "user.email=test@example.com",
+        "commit",
+        "--quiet",
+        "--allow-empty",
+        "-m",
+        "real work",
+    ]);
+    git(&[
+        "-C",
+        "site",
+        "push",
+        "--quiet",
+        "origin",
+        "HEAD:refs/heads/main",
+    ]);
+    let deploy_main = ["deploy", "site", "--git", "--branch", "main"];
+    let output = run(dir.path(), &deploy_main);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--force"));
+    assert_eq!(
+        git(&[
+            "--git-dir",
+            "remote.git",
+            "log",
+            "-1",
+            "--format=%s",
+            "main"
+        ])
+        .trim(),
+        "real work"
+    );
+    assert!(run(dir.path(), &[&deploy_main[..], &["--force"]].concat())
+        .status
+        .success());
+    assert!(run(dir.path(), &deploy_main).status.success());
+}
+
+#[test]
+fn test_user_defaults_prefill_generation() {
+    use portfolio_arcade::models::GitHubDefaults;
+
+    let dir = tempfile::tempdir().unwrap();
+    let defaults_path = dir.path().join("config.toml");
+    std::fs::write(
+        &defaults_path,
+        r#"
+theme = "wii"
+cms = "none"
+port = 4321
+
+[author]
+name = "ada lovelace"
+title = "analyst"
+
+[social]
+github = "https://github.com/ada"
+"#,
+    )
+    .unwrap();
+    let config_path = dir.path().join("portfolio.json");
+    std::fs::write(&config_path, r#"{ "user": { "title": "engineer" } }"#).unwrap();
+
+    let output = arcade()
+        .args(["--json", "init", "site", "--config"])
+        .arg(&config_path)
+        .env("PORTFOLIO_ARCADE_CONFIG", &defaults_path)
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let data = std::fs::read_to_string(dir.path().join("site/public/data/portfolio.json")).unwrap();
+    let data: serde_json::Value = serde_json::from_str(&data).unwrap();
+    assert_eq!(data["theme"], "wii");
+    assert_eq!(data["cms"], "none");
+    assert_eq!(data["dev_port"], 4321);
+    assert_eq!(data["user"]["name"], "ada lovelace");
+    // the config file still wins over the defaults
+    assert_eq!(data["user"]["title"], "engineer");
+    // the old [social] table still loads, without the placeholder links
+    assert_eq!(data["user"]["social"][0]["platform"], "github");
+    assert_eq!(data["user"]["social"][0]["url"], "https://github.com/ada");
+    assert_eq!(data["user"]["social"].as_array().unwrap().len(), 1);
+
+    let token_path = dir.path().join("token");
+    std::fs::write(&token_path, "ghp_example\n").unwrap();
+    let github = GitHubDefaults {
+        token_file: Some(token_path),
+        ..Default::default()
+    };
+    let (token, source) = github.token_with_source().unwrap();
+    assert_eq!(token, "ghp_example");
+    assert!(source.starts_with("token_file "), "{}", source);
+
+    // a broken defaults file is a config error
+    std::fs::write(&defaults_path, "port = \"not a port\"").unwrap();
+    let output = arcade()
+        .args(["init", "other", "--theme", "ps3"])
+        .env("PORTFOLIO_ARCADE_CONFIG", &defaults_path)
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(3));
+}
+
+#[test]
+fn test_dry_run_reports_without_writing() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = run(
+        dir.path(),
+        &["--json", "init", "preview", "--dry-run", "--cms", "decap"],
+    );
+    assert!(output.status.success());
+    assert!(!dir.path().join("preview").exists());
+
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["dry_run"], true);
+    let action = |path: &str| {
+        result["files"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|f| f["path"] == path)
+            .map(|f| f["action"].as_str().unwrap().to_string())
+    };
+    for written in [
+        "package.json",
+        "app/layout.tsx",
+        "public/data/portfolio.json",
+        "README.md",
+        "public/admin/config.yml",
+        "content/projects/sample-project.md",
+    ] {
+        assert_eq!(action(written).as_deref(), Some("write"), "{}", written);
+    }
+    assert_eq!(action("tsconfig.json").as_deref(), Some("copy"));
+    assert_eq!(result["portfolio_data"]["theme"], "ps5");
+    assert_eq!(result["portfolio_data"]["cms"], "decap");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_failed_generation_leaves_nothing_behind() {
+    use portfolio_arcade::generator::TemplateGenerator;
+
+    let dir = tempfile::tempdir().unwrap();
+    let target = dir.path().join("site");
+
+    // a template whose second file can't be copied fails halfway through writing
+    let template = tempfile::tempdir().unwrap();
+    std::fs::write(template.path().join("package.json"), "{}").unwrap();
+    std::fs::create_dir_all(template.path().join("app")).unwrap();
+    std::os::unix::fs::symlink("/nonexistent", template.path().join("zz-broken")).unwrap();
+
+    let mut generator = TemplateGenerator::with_target_dir(
+        "site".to_string(),
+        PortfolioConfig::default(),
+        target.clone(),
+    )
+    .unwrap();
+    generator.source_dir = template.path().to_path_buf();
+
+    assert!(generator.generate().await.is_err());
+    assert!(!target.exists());
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+
+    // the same generator succeeds once the template is fixed
+    std::fs::remove_file(template.path().join("zz-broken")).unwrap();
+    generator.generate().await.unwrap();
+    assert!(target.join("public/data/portfolio.json").exists());
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn test_force_generation_into_existing_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let site = dir.path().join("site");
+    let read = |path: &str| std::fs::read_to_string(site.join(path)).unwrap();
+
+    assert!(run(dir.path(), &["init", "site", "--theme", "ps3"])
+        .status
+        .success());
+    std::fs::create_dir_all(site.join(".github/workflows")).unwrap();
+    std::fs::write(site.join(".github/workflows/ci.yml"), "on: push").unwrap();
+    std::fs::write(site.join("README.md"), "my own readme").unwrap();
+    let mut package: serde_json::Value = serde_json::from_str(&read("package.json")).unwrap();
+    package["scripts"]["deploy"] = "custom".into();
+    std::fs::write(site.join("package.json"), package.to_string()).unwrap();
+
+    // the policy flag on its own is a usage error
+    assert_eq!(
+        run(dir.path(), &["init", "site", "--existing", "merge"])
+            .status
+            .code(),
+        Some(2)
+    );
+
+    let output = run(
+        dir.path(),
+        &[
+            "--json",
+            "init",
+            "site",
+            "--theme",
+            "ps3",
+            "--force",
+            "--existing",
+            "merge",
+        ],
+    );
+    assert!(output.status.success());
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        result["files"]["merged"],
+        serde_json::json!(["package.json"])
+    );
+    assert_eq!(result["files"]["skipped"], serde_json::json!(["README.md"]));
+    let package: serde_json::Value = serde_json::from_str(&read("package.json")).unwrap();
+    assert_eq!(package["scripts"]["deploy"], "custom");
+    assert_eq!(package["name"], "site");
+    assert_eq!(read("README.md"), "my own readme");
+
+    let output = run(
+        dir.path(),
+        &[
+            "--json",
+            "init",
+            "site",
+            "--theme",
+            "ps3",
+            "--force",
+            "--existing",
+            "skip-existing",
+        ],
+    );
+    assert!(output.status.success());
+    assert_eq!(read("README.md"), "my own readme");
+
+    let output = run(
+        dir.path(),
+        &["--json", "init", "site", "--theme", "ps3", "--force"],
+    );
+    assert!(output.status.success());
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let replaced = result["files"]["replaced"].as_array().unwrap();
+    assert!(replaced.contains(&"README.md".into()));
+    assert!(replaced.contains(&"package.json".into()));
+    assert!(result["files"]["created"].as_array().unwrap().is_empty());
+    assert_ne!(read("README.md"), "my own readme");
+    // files the generator doesn't produce are never touched
+    assert_eq!(read(".github/workflows/ci.yml"), "on: push");
+}
+
+#[test]
+fn test_force_skip_existing_records_what_is_on_disk() {
+    use portfolio_arcade::generator::manifest::Manifest;
+    use portfolio_arcade::generator::GeneratedProject;
+
+    let dir = tempfile::tempdir().unwrap();
+    let site = dir.path().join("site");
+
+    assert!(run(dir.path(), &["init", "site", "--theme", "ps5"])
+        .status
+        .success());
+    let data_path = site.join("public/data/portfolio.json");
+    let mut data: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&data_path).unwrap()).unwrap();
+    data["user"]["name"] = "hand edited".into();
+    std::fs::write(&data_path, serde_json::to_string_pretty(&data).unwrap()).unwrap();
+
+    let output = run(
+        dir.path(),
+        &[
+            "--json",
+            "init",
+            "site",
+            "--theme",
+            "ps5",
+            "--force",
+            "--existing",
+            "skip-existing",
+        ],
+    );
+    assert!(output.status.success());
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(result["files"]["skipped"]
+        .as_array()
+        .unwrap()
+        .contains(&"public/data/portfolio.json".into()));
+
+    // the kept data file is hashed as it is and its config is the one recorded
+    let manifest = Manifest::load(&site).unwrap().unwrap();
+    assert!(manifest.edited_files(&site).is_empty());
+    assert_eq!(manifest.config.user.name, "hand edited");
+    let config = GeneratedProject::open(&site)
+        .unwrap()
+        .load_config()
+        .unwrap();
+    assert_eq!(config.user.name, "hand edited");
+}
+
+#[tokio::test]
+async fn test_upgrade_three_way_merges_template_changes() {
+    use portfolio_arcade::generator::plan::ExistingFiles;
+    use portfolio_arcade::generator::upgrade::{
+        apply_upgrade, plan_upgrade, TemplateBase, UpgradeReport,
+    };
+    use portfolio_arcade::generator::TemplateGenerator;
+    use std::path::PathBuf;
+
+    let dir = tempfile::tempdir().unwrap();
+    let site = dir.path().join("site");
+    let template = tempfile::tempdir().unwrap();
+    let write = |root: &std::path::Path, path: &str, content: &str| {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    };
+
+    write(template.path(), "package.json", r#"{ "name": "template" }"#);
+    write(template.path(), "app/page.tsx", "line1\nline2\nline3\n");
+    write(template.path(), "components/card.tsx", "card\n");
+    write(template.path(), "components/old.tsx", "old\n");
+
+    let config = PortfolioConfig {
+        cms: portfolio_arcade::models::CmsType::None,
+        ..Default::default()
+    };
+    let mut generator =
+        TemplateGenerator::with_target_dir("site".to_string(), config.clone(), site.clone())
+            .unwrap();
+    generator.source_dir = template.path().to_path_buf();
+    generator.generate().await.unwrap();
+
+    // the user customizes two components
+    write(&site, "app/page.tsx", "mine1\nline2\nline3\n");
+    write(&site, "components/card.tsx", "my card\n");
+
+    // and the template ships fixes
+    write(template.path(), "app/page.tsx", "line1\nline2\nfixed3\n");
+    write(template.path(), "components/card.tsx", "template card\n");
+    write(template.path(), "components/new.tsx", "new\n");
+    std::fs::remove_file(template.path().join("components/old.tsx")).unwrap();
+
+    let mut upgraded = TemplateGenerator::with_existing_policy(
+        "site".to_string(),
+        config,
+        site.clone(),
+        Some(ExistingFiles::Overwrite),
+    )
+    .unwrap();
+    upgraded.source_dir = template.path().to_path_buf();
+    let plan = upgraded.plan().unwrap();
+
+    let base = TemplateBase::load(&site).unwrap().unwrap();
+    let steps = plan_upgrade(&site, &base, &plan).unwrap();
+    apply_upgrade(&site, &steps).unwrap();
+    let report = UpgradeReport::from_steps(&steps);
+
+    assert_eq!(report.merged, [PathBuf::from("app/page.tsx")]);
+    assert_eq!(report.conflicts, [PathBuf::from("components/card.tsx")]);
+    assert_eq!(report.added, [PathBuf::from("components/new.tsx")]);
+    assert_eq!(report.removed, [PathBuf::from("components/old.tsx")]);
+    assert!(report.updated.is_empty());
+
+    let read = |path: &str| std::fs::read_to_string(site.join(path)).unwrap();
+    assert_eq!(read("app/page.tsx"), "mine1\nline2\nfixed3\n");
+    let card = read("components/card.tsx");
+    assert!(card.contains("<<<<<<< ours\nmy card\n=======\ntemplate card\n>>>>>>> theirs"));
+    assert_eq!(read("components/new.tsx"), "new\n");
+    assert!(!site.join("components/old.tsx").exists());
+}
+
+#[test]
+fn test_upgrade_without_template_changes_is_a_no_op() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(run(dir.path(), &["init", "site", "--theme", "wii"])
+        .status
+        .success());
+    let base = dir.path().join("site/.portfolio-arcade-base.json.gz");
+    assert!(base.exists());
+
+    let output = run(dir.path(), &["--json", "upgrade", "site"]);
+    assert!(output.status.success());
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    for outcome in ["updated", "added", "removed", "merged", "conflicts", "kept"] {
+        assert!(
+            result["files"][outcome].as_array().unwrap().is_empty(),
+            "{}",
+            outcome
+        );
+    }
+
+    // bases written uncompressed by older versions still load, and are replaced
+    let mut json = String::new();
+    std::io::Read::read_to_string(
+        &mut flate2::read::GzDecoder::new(std::fs::File::open(&base).unwrap()),
+        &mut json,
+    )
+    .unwrap();
+    let legacy = dir.path().join("site/.portfolio-arcade-base.json");
+    std::fs::write(&legacy, json).unwrap();
+    std::fs::remove_file(&base).unwrap();
+    assert!(run(dir.path(), &["upgrade", "site"]).status.success());
+    assert!(base.exists() && !legacy.exists());
+
+    std::fs::remove_file(&base).unwrap();
+    let output = run(dir.path(), &["upgrade", "site"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--record-base"));
+
+    // recording a base only writes the base, from the portfolio's own config
+    let data = std::fs::read(dir.path().join("site/public/data/portfolio.json")).unwrap();
+    assert!(run(dir.path(), &["upgrade", "site", "--record-base"])
+        .status
+        .success());
+    assert!(base.exists());
+    assert_eq!(
+        std::fs::read(dir.path().join("site/public/data/portfolio.json")).unwrap(),
+        data
+    );
+    assert!(!dir.path().join("site/site").exists());
+    assert!(!run(dir.path(), &["upgrade", "site", "--record-base"])
+        .status
+        .success());
+    let output = run(dir.path(), &["--json", "upgrade", "site"]);
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(result["files"]["updated"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_manifest_records_generation() {
+    use portfolio_arcade::commands::doctor::{check_manifest, CheckStatus};
+    use portfolio_arcade::generator::manifest::Manifest;
+    use portfolio_arcade::generator::GeneratedProject;
+    use std::path::{Path, PathBuf};
+
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("portfolio.json");
+    std::fs::write(
+        &config_path,
+        r#"{
+            "user": { "name": "grace" },
+            "projects": [{
+                "id": "compiler",
+                "title": "compiler",
+                "date": "1952-05",
+                "thumbnail": "/projects/compiler.png",
+                "links": { "demo": "https://example.com/demo" }
+            }]
+        }"#,
+    )
+    .unwrap();
+    let output = run(
+        dir.path(),
+        &[
+            "init",
+            "site",
+            "--theme",
+            "wii",
+            "--cms",
+            "none",
+            "--config",
+            config_path.to_str().unwrap(),
+        ],
+    );
+    assert!(output.status.success());
+
+    let site = dir.path().join("site");
+    let manifest = Manifest::load(&site).unwrap().unwrap();
+    assert_eq!(manifest.tool_version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(manifest.theme, "wii");
+    assert_eq!(manifest.template_hash.len(), 64);
+    assert_eq!(manifest.config.user.name, "grace");
+    assert!(manifest.files.contains_key("package.json"));
+    assert!(manifest.files.contains_key("public/data/portfolio.json"));
+    assert!(manifest.edited_files(&site).is_empty());
+
+    // the wii data file has no room for dates, thumbnails or demo links, the manifest does
+    let config = GeneratedProject::open(&site)
+        .unwrap()
+        .load_config()
+        .unwrap();
+    assert_eq!(config.projects[0].date, "1952-05");
+    assert_eq!(config.projects[0].thumbnail, "/projects/compiler.png");
+
+    // changes made through the cli keep the manifest in step
+    let output = run(
+        dir.path(),
+        &["add-project", "--title", "linker", "--path", "site"],
+    );
+    assert!(output.status.success());
+    let config = GeneratedProject::open(&site)
+        .unwrap()
+        .load_config()
+        .unwrap();
+    assert_eq!(config.projects.len(), 2);
+    assert_eq!(config.projects[0].date, "1952-05");
+
+    // hand edits show up as edited files
+    std::fs::write(
+        site.join("app/page.tsx"),
+        "export default function Page() {}",
+    )
+    .unwrap();
+    let manifest = Manifest::load(&site).unwrap().unwrap();
+    assert_eq!(
+        manifest.edited_files(&site),
+        [PathBuf::from("app/page.tsx")]
+    );
+    assert!(!manifest.is_edited(&site, Path::new("public/data/portfolio.json")));
+
+    // a lost manifest is recorded again from the portfolio's own config
+    std::fs::remove_file(site.join(".portfolio-arcade.json")).unwrap();
+    let check = check_manifest(&site).unwrap();
+    assert_eq!(check.status, CheckStatus::Warn);
+    let hint = check.hint.unwrap();
+    assert!(
+        hint.contains(&format!("upgrade {} --record-base", site.display())),
+        "{}",
+        hint
+    );
+    assert!(run(dir.path(), &["upgrade", "site", "--record-base"])
+        .status
+        .success());
+    assert_eq!(check_manifest(&site).unwrap().status, CheckStatus::Pass);
+    let manifest = Manifest::load(&site).unwrap().unwrap();
+    assert_eq!(manifest.config.projects.len(), 2);
+    assert!(!run(dir.path(), &["upgrade", "site", "--record-base"])
+        .status
+        .success());
+}
+
+#[test]
+fn test_roster_parsing() {
+    use portfolio_arcade::models::Roster;
+
+    let csv = "Name,Author,Title,Theme,Repos\n\
+               ada-site, ada lovelace ,analyst,wii,\"https://github.com/ada/engine; https://github.com/ada/notes\"\n\
+               grace-site,grace hopper,,ps3,\n";
+    let entries = Roster::parse_csv(csv).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].name, "ada-site");
+    assert_eq!(entries[0].author.as_deref(), Some("ada lovelace"));
+    assert_eq!(
+        entries[0].repos,
+        [
+            "https://github.com/ada/engine",
+            "https://github.com/ada/notes"
+        ]
+    );
+    assert_eq!(entries[1].title, None);
+    assert!(entries[1].repos.is_empty());
+
+    let json = r#"[
+        { "name": "ada-site", "repos": ["https://github.com/ada/engine"] },
+        { "project_name": "grace-site", "github": "https://github.com/grace/cobol" }
+    ]"#;
+    let entries = Roster::parse_json(json).unwrap();
+    assert_eq!(entries[0].repos, ["https://github.com/ada/engine"]);
+    assert_eq!(entries[1].name, "grace-site");
+    assert_eq!(entries[1].repos, ["https://github.com/grace/cobol"]);
+
+    assert!(Roster::parse_csv("author,theme\nada,wii\n").is_err());
+}
+
+#[test]
+fn test_batch_generates_one_portfolio_per_row() {
+    let dir = tempfile::tempdir().unwrap();
+    let roster = dir.path().join("roster.csv");
+    std::fs::write(
+        &roster,
+        "name,author,title,theme\n\
+         ada-site,ada lovelace,analyst,wii\n\
+         bad-site,nobody,,gamecube\n\
+         grace-site,grace hopper,admiral,ps3\n",
+    )
+    .unwrap();
+
+    let output = run(
+        dir.path(),
+        &[
+            "--json",
+            "batch",
+            "roster.csv",
+            "--output",
+            "class",
+            "--cms",
+            "none",
+        ],
+    );
+    assert_eq!(output.status.code(), Some(9));
+
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["ok"], false);
+    assert_eq!(result["generated"], 2);
+    assert_eq!(result["failed"], 1);
+    assert_eq!(result["portfolios"][1]["name"], "bad-site");
+    assert_eq!(result["portfolios"][1]["row"], 2);
+    assert!(result["portfolios"][1]["error"]
+        .as_str()
+        .unwrap()
+        .contains("gamecube"));
+
+    let data = std::fs::read_to_string(
+        dir.path()
+            .join("class/grace-site/public/data/portfolio.json"),
+    )
+    .unwrap();
+    let data: serde_json::Value = serde_json::from_str(&data).unwrap();
+    assert_eq!(data["theme"], "ps3");
+    assert_eq!(data["user"]["name"], "grace hopper");
+    assert_eq!(data["user"]["title"], "admiral");
+    assert!(dir.path().join("class/ada-site/package.json").exists());
+    assert!(!dir.path().join("class/bad-site").exists());
+}
+
+#[test]
+fn test_all_themes_generation() {
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("portfolio.json");
+    std::fs::write(
+        &config_path,
+        r#"{ "user": { "name": "ada <lovelace>" }, "cms": "none" }"#,
+    )
+    .unwrap();
+
+    let output = arcade()
+        .args(["--json", "init", "site", "--all-themes", "--config"])
+        .arg(&config_path)
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["themes"].as_array().unwrap().len(), 3);
+
+    let site = dir.path().join("site");
+    for theme in ["ps3", "ps5", "wii"] {
+        let data =
+            std::fs::read_to_string(site.join(theme).join("public/data/portfolio.json")).unwrap();
+        let data: serde_json::Value = serde_json::from_str(&data).unwrap();
+        assert_eq!(data["theme"], theme);
+        assert_eq!(data["user"]["name"], "ada <lovelace>");
+        assert!(!data["projects"].as_array().unwrap().is_empty());
+    }
+
+    let landing = std::fs::read_to_string(site.join("index.html")).unwrap();
+    for link in ["href=\"./ps3/\"", "href=\"./ps5/\"", "href=\"./wii/\""] {
+        assert!(landing.contains(link), "{}", link);
+    }
+    assert!(landing.contains("ada &lt;lovelace&gt;"));
+    // nothing is left staged next to the project
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 2);
+
+    // a hand-edited landing page follows the --existing policy like every other file
+    std::fs::write(site.join("index.html"), "<h1>mine</h1>").unwrap();
+    let regenerate = |policy: &str| {
+        let output = arcade()
+            .args([
+                "--json",
+                "init",
+                "site",
+                "--all-themes",
+                "--force",
+                "--existing",
+            ])
+            .arg(policy)
+            .arg("--config")
+            .arg(&config_path)
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap()
+    };
+    let result = regenerate("skip-existing");
+    assert_eq!(
+        result["landing_page_files"]["skipped"],
+        serde_json::json!(["index.html"])
+    );
+    assert_eq!(
+        std::fs::read_to_string(site.join("index.html")).unwrap(),
+        "<h1>mine</h1>"
+    );
+    let result = regenerate("overwrite");
+    assert_eq!(
+        result["landing_page_files"]["replaced"],
+        serde_json::json!(["index.html"])
+    );
+    assert!(std::fs::read_to_string(site.join("index.html"))
+        .unwrap()
+        .contains("href=\"./ps5/\""));
+}
+
+#[test]
+fn test_cartridge_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("portfolio.json");
+    std::fs::write(
+        &config_path,
+        r#"{
+            "user": { "name": "ada", "avatar": "/images/avatar.png" },
+            "projects": [{
+                "id": "engine",
+                "title": "analytical engine",
+                "thumbnail": "/projects/engine.png",
+                "screenshots": ["https://example.com/remote.png"]
+            }]
+        }"#,
+    )
+    .unwrap();
+    let output = run(
+        dir.path(),
+        &[
+            "init",
+            "site",
+            "--theme",
+            "ps5",
+            "--cms",
+            "none",
+            "--config",
+            "portfolio.json",
+        ],
+    );
+    assert!(output.status.success());
+    let avatar = [0x89, b'P', b'N', b'G', 0, 1, 2, 3];
+    std::fs::create_dir_all(dir.path().join("site/public/images")).unwrap();
+    std::fs::write(dir.path().join("site/public/images/avatar.png"), avatar).unwrap();
+
+    let output = run(
+        dir.path(),
+        &["--json", "pack", "site", "--output", "site.arcade"],
+    );
+    assert!(output.status.success());
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["assets"], serde_json::json!(["images/avatar.png"]));
+    // the thumbnail was never added to the site, so it can only be referenced
+    assert_eq!(
+        result["missing_assets"],
+        serde_json::json!(["projects/engine.png"])
+    );
+
+    let output = run(dir.path(), &["init", "copy", "--from", "site.arcade"]);
+    assert!(output.status.success());
+    let read_data = |site: &str| -> serde_json::Value {
+        let path = dir.path().join(site).join("public/data/portfolio.json");
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    };
+    assert_eq!(read_data("site"), read_data("copy"));
+    assert_eq!(
+        std::fs::read(dir.path().join("copy/public/images/avatar.png")).unwrap(),
+        avatar
+    );
+
+    let output = run(
+        dir.path(),
+        &["unpack", "site.arcade", "--output", "unpacked"],
+    );
+    assert!(output.status.success());
+    let config: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(dir.path().join("unpacked/portfolio.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(config["theme"], "ps5");
+    assert_eq!(config["user"]["avatar"], "/images/avatar.png");
+    assert!(dir
+        .path()
+        .join("unpacked/public/images/avatar.png")
+        .exists());
+    assert!(!run(
+        dir.path(),
+        &["unpack", "site.arcade", "--output", "unpacked"]
+    )
+    .status
+    .success());
+
+    // a packed config file carries what it says, not the packer's user defaults
+    let defaults_path = dir.path().join("config.toml");
+    std::fs::write(
+        &defaults_path,
+        "theme = \"wii\"\n[author]\ntitle = \"staff engineer\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("partial.json"),
+        r#"{ "user": { "name": "ada" } }"#,
+    )
+    .unwrap();
+    let output = arcade()
+        .args([
+            "pack",
+            "--config",
+            "partial.json",
+            "--output",
+            "partial.arcade",
+        ])
+        .env("PORTFOLIO_ARCADE_CONFIG", &defaults_path)
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(run(
+        dir.path(),
+        &["unpack", "partial.arcade", "--output", "partial"]
+    )
+    .status
+    .success());
+    let config: PortfolioConfig = serde_json::from_str(
+        &std::fs::read_to_string(dir.path().join("partial/portfolio.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(config.user.name, "ada");
+    assert_eq!(config.theme, PortfolioConfig::default().theme);
+    assert_eq!(config.user.title, PortfolioConfig::default().user.title);
+}
+
+#[test]
+fn test_skills_seeded_from_languages_and_emitted_by_every_theme() {
+    use portfolio_arcade::github::skills_from_languages;
+    use portfolio_arcade::models::Skill;
+    use std::collections::HashMap;
+
+    let languages = HashMap::from([
+        ("Rust".to_string(), 80_000),
+        ("Shell".to_string(), 10_000),
+        ("Makefile".to_string(), 1_000),
+    ]);
+    let skills = skills_from_languages(&languages);
+    let names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+    // under 5% of the code isn't a skill
+    assert_eq!(names, ["Rust", "Shell"]);
+    assert_eq!(skills[0].proficiency, 100);
+    assert!(skills[1].proficiency < 20);
+    assert!(skills.iter().all(|s| s.category == "language"));
+
+    let mut config = PortfolioConfig::default();
+    config.add_skills(skills);
+    // already listed skills keep what the user set
+    config.add_skills(vec![Skill {
+        name: "rust".to_string(),
+        category: "language".to_string(),
+        proficiency: 5,
+    }]);
+    config.add_skills(vec![Skill {
+        name: "figma".to_string(),
+        category: "design tools".to_string(),
+        proficiency: 60,
+    }]);
+    assert_eq!(config.skills.len(), 3);
+    assert_eq!(config.skills[0].proficiency, 100);
+
+    for theme in Theme::available_themes() {
+        let data = theme.portfolio_data(&config).unwrap();
+        assert_eq!(data["skills"].as_array().unwrap().len(), 3, "{}", theme.id);
+        assert_eq!(
+            theme.recover_skills(&data["skills"]),
+            config.skills,
+            "{}",
+            theme.id
+        );
+    }
+
+    // json resume skills are sections of keywords, one per category
+    let resume = portfolio_arcade::models::JsonResume::from_config(&config);
+    let json = serde_json::to_value(&resume).unwrap();
+    assert_eq!(json["skills"][0]["name"], "language");
+    assert_eq!(json["skills"][0]["level"], "expert");
+    assert_eq!(
+        json["skills"][0]["keywords"],
+        serde_json::json!(["Rust", "Shell"])
+    );
+    let back = resume.into_config(PortfolioConfig::default());
+    let names: Vec<&str> = back.skills.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["Rust", "Shell", "figma"]);
+    assert_eq!(back.skills[2].category, "design tools");
+}
+
+#[test]
+fn test_experience_and_education_emitted_by_every_theme() {
+    use portfolio_arcade::models::{JsonResume, TimelineEntry};
+
+    let config = PortfolioConfig {
+        experience: vec![
+            TimelineEntry {
+                organization: "Arcade Labs".to_string(),
+                role: "Senior Engineer".to_string(),
+                start_date: "2022-03".to_string(),
+                end_date: String::new(),
+                highlights: vec!["led the renderer rewrite".to_string()],
+            },
+            TimelineEntry {
+                organization: "Pixel Co".to_string(),
+                role: "Engineer".to_string(),
+                start_date: "2019".to_string(),
+                end_date: "2022".to_string(),
+                highlights: Vec::new(),
+            },
+        ],
+        education: vec![TimelineEntry {
+            organization: "State University".to_string(),
+            role: "BSc in Computer Science".to_string(),
+            start_date: "2015".to_string(),
+            end_date: "2019".to_string(),
+            highlights: vec!["graphics".to_string(), "compilers".to_string()],
+        }],
+        ..Default::default()
+    };
+    assert_eq!(config.experience[0].period(), "2022-03 - present");
+
+    for theme in Theme::available_themes() {
+        let data = theme.portfolio_data(&config).unwrap();
+        assert_eq!(
+            theme.recover_timeline(&data["experience"]),
+            config.experience,
+            "{}",
+            theme.id
+        );
+        assert_eq!(
+            theme.recover_timeline(&data["education"]),
+            config.education,
+            "{}",
+            theme.id
+        );
+    }
+
+    let ps3 = Theme::find_by_id("ps3")
+        .unwrap()
+        .portfolio_data(&config)
+        .unwrap();
+    assert_eq!(ps3["experience"][0]["id"], "experience-1");
+    assert_eq!(ps3["experience"][0]["profilePriority"][0], "recruiter");
+    assert_eq!(ps3["experience"][0]["icon"], "briefcase");
+    assert_eq!(ps3["education"][0]["icon"], "graduation-cap");
+    assert_eq!(
+        ps3["education"][0]["description"],
+        "- graphics\n- compilers"
+    );
+
+    // work and education sections of a json resume
+    let resume = JsonResume::from_config(&config);
+    let json = serde_json::to_value(&resume).unwrap();
+    assert_eq!(json["work"][0]["name"], "Arcade Labs");
+    assert!(json["work"][0].get("endDate").is_none());
+    assert_eq!(json["education"][0]["courses"][1], "compilers");
+    let back = resume.into_config(PortfolioConfig::default());
+    assert_eq!(back.experience, config.experience);
+    assert_eq!(back.education, config.education);
+
+    let resume: JsonResume = serde_json::from_value(serde_json::json!({
+        "education": [{ "institution": "Tech", "studyType": "Master", "area": "Design" }]
+    }))
+    .unwrap();
+    let config = resume.into_config(PortfolioConfig::default());
+    assert_eq!(config.education[0].role, "Master in Design");
+}
+
+#[test]
+fn test_posts_authored_as_markdown() {
+    use portfolio_arcade::generator::GeneratedProject;
+    use portfolio_arcade::models::Post;
+
+    // what decap cms writes: bare dates and block lists
+    let post = Post::from_markdown(
+        "hello-world",
+        "---\ntitle: Hello, world\ndate: 2024-02-01\ntags:\n  - rust\n  - 'cli'\n---\n\n# hi\n\nfirst post\n",
+    )
+    .unwrap();
+    assert_eq!(post.title, "Hello, world");
+    assert_eq!(post.date, "2024-02-01");
+    assert_eq!(post.tags, ["rust", "cli"]);
+    assert_eq!(post.body, "# hi\n\nfirst post");
+    assert_eq!(
+        Post::from_markdown("hello-world", &post.to_markdown()).unwrap(),
+        post
+    );
+    assert!(Post::from_markdown("untitled", "just text").is_err());
+
+    let linked = Post {
+        title: "Why Rust?".to_string(),
+        date: "2023-06-10".to_string(),
+        summary: "a talk write-up".to_string(),
+        url: Some("https://blog.example.com/why-rust".to_string()),
+        tags: vec!["rust".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(linked.file_name(), "why-rust.md");
+
+    let config = PortfolioConfig {
+        posts: vec![post.clone(), linked.clone()],
+        ..Default::default()
+    };
+    for theme in Theme::available_themes() {
+        let data = theme.portfolio_data(&config).unwrap();
+        let recovered = theme.recover_posts(&data["posts"]);
+        assert_eq!(recovered[0], post, "{}", theme.id);
+        assert_eq!(recovered[1].url, linked.url, "{}", theme.id);
+        assert_eq!(recovered[1].summary, linked.summary, "{}", theme.id);
+    }
+
+    // a config file's content/posts/ joins its posts, then generation writes them back out
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("portfolio.json");
+    std::fs::write(
+        &config_path,
+        serde_json::json!({ "user": { "name": "ada" }, "posts": [linked] }).to_string(),
+    )
+    .unwrap();
+    std::fs::create_dir_all(dir.path().join("content/posts")).unwrap();
+    std::fs::write(
+        dir.path().join("content/posts/hello-world.md"),
+        post.to_markdown(),
+    )
+    .unwrap();
+    let output = arcade()
+        .args([
+            "init", "site", "--theme", "ps3", "--cms", "none", "--config",
+        ])
+        .arg(&config_path)
+        .current_dir(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let site = dir.path().join("site");
+    assert!(site.join("content/posts/hello-world.md").exists());
+    assert!(site.join("content/posts/why-rust.md").exists());
+    let mut project = GeneratedProject::open(&site).unwrap();
+    let config = project.load_config().unwrap();
+    assert_eq!(config.posts.len(), 2);
+    assert!(!project.posts_changed(&config));
+
+    // a post written after generation reaches portfolio.json on the next save
+    std::fs::write(
+        site.join("content/posts/newer.md"),
+        "---\ntitle: \"Newer\"\ndate: \"2025-01-01\"\n---\nbody\n",
+    )
+    .unwrap();
+    let config = project.load_config().unwrap();
+    assert_eq!(config.posts[0].title, "Newer");
+    assert!(project.posts_changed(&config));
+    project.save_config(&config).unwrap();
+    let project = GeneratedProject::open(&site).unwrap();
+    assert_eq!(project.data["posts"][0]["id"], "post-newer");
+    assert_eq!(project.data["posts"].as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn test_social_links_are_an_ordered_list() {
+    use portfolio_arcade::models::{JsonResume, SocialLink, SocialLinks, SocialPlatform};
+
+    // configs written before the list still load, in the old field order
+    let legacy: SocialLinks = serde_json::from_value(serde_json::json!({
+        "twitter": "https://twitter.com/ada",
+        "email": "ada@example.com",
+        "website": "",
+    }))
+    .unwrap();
+    assert_eq!(legacy.len(), 2);
+    assert_eq!(legacy[0].platform, SocialPlatform::Email);
+    assert_eq!(legacy[0].url, "mailto:ada@example.com");
+    assert_eq!(legacy[1].label, "Twitter");
+
+    let social: SocialLinks = serde_json::from_value(serde_json::json!([
+        { "platform": "bluesky", "url": "https://bsky.app/profile/ada.dev" },
+        { "platform": "custom", "url": "https://ada.substack.com", "label": "Newsletter" },
+    ]))
+    .unwrap();
+    assert_eq!(social[0].label, "Bluesky");
+    assert_eq!(social[1].label, "Newsletter");
+    assert!(serde_json::from_value::<SocialLinks>(serde_json::json!([
+        { "platform": "myspace", "url": "https://myspace.com/ada" }
+    ]))
+    .is_err());
+
+    for (url, platform) in [
+        ("https://hachyderm.io/@ada", SocialPlatform::Mastodon),
+        ("https://www.youtube.com/@ada", SocialPlatform::Youtube),
+        ("https://ada.itch.io", SocialPlatform::Itchio),
+        ("https://dev.to/ada", SocialPlatform::Devto),
+        ("ada@example.com", SocialPlatform::Email),
+        ("https://ada.dev", SocialPlatform::Website),
+    ] {
+        assert_eq!(SocialLink::from_url(url).platform, platform, "{}", url);
+    }
+
+    // every theme's portfolio.json carries the list as is
+    let mut config = PortfolioConfig::default();
+    config.user.social = social.clone();
+    for theme in Theme::available_themes() {
+        let data = theme.portfolio_data(&config).unwrap();
+        assert_eq!(data["user"]["social"][1]["platform"], "custom");
+        let user: portfolio_arcade::models::UserInfo =
+            serde_json::from_value(data["user"].clone()).unwrap();
+        assert_eq!(user.social, social);
+    }
+
+    // platforms without a basics field become json resume profiles and come back typed
+    let resume = JsonResume::from_config(&config);
+    let json = serde_json::to_value(&resume).unwrap();
+    assert_eq!(json["basics"]["profiles"][0]["network"], "Bluesky");
+    assert_eq!(json["basics"]["profiles"][1]["network"], "Newsletter");
+    let back = resume.into_config(PortfolioConfig::default());
+    assert_eq!(back.user.social[0].platform, SocialPlatform::Bluesky);
+    assert_eq!(back.user.social[1].platform, SocialPlatform::Custom);
+    assert_eq!(back.user.social[1].label, "Newsletter");
+}
+
+#[test]
+fn test_repo_metadata_is_typed_and_extra_cannot_overwrite_adapter_fields() {
+    let mut config = PortfolioConfig::default();
+    let repo = RepoMetadata {
+        stars: 12,
+        forks: 3,
+        topics: vec!["rust".to_string(), "cli".to_string()],
+        language: Some("Rust".to_string()),
+        pushed_at: Some("2024-05-01T10:00:00Z".to_string()),
+        license: Some("MIT".to_string()),
+        archived: true,
+    };
+    let project = &mut config.projects[0];
+    project.repo = Some(repo.clone());
+    project
+        .extra
+        .insert("id".to_string(), serde_json::json!("hijacked"));
+    project
+        .extra
+        .insert("title".to_string(), serde_json::json!("hijacked"));
+    project
+        .extra
+        .insert("badge".to_string(), serde_json::json!("new"));
+
+    for theme in Theme::available_themes() {
+        let adapted = theme.adapt_projects(&config.projects).unwrap();
+        let item = &adapted[0];
+        assert_eq!(
+            item["id"], config.projects[0].id,
+            "{} let extra replace id",
+            theme.id
+        );
+        assert_eq!(item["badge"], "new", "{} dropped a user field", theme.id);
+        assert_eq!(item["stars"], 12, "{}", theme.id);
+        assert_eq!(item["topics"], serde_json::json!(["rust", "cli"]));
+        assert_eq!(item["license"], "MIT");
+        assert_eq!(item["archived"], true);
+
+        let recovered = theme.recover_projects(&adapted).unwrap();
+        assert_eq!(recovered[0].title, config.projects[0].title, "{}", theme.id);
+        assert_eq!(recovered[0].repo.as_ref(), Some(&repo), "{}", theme.id);
+        assert_eq!(recovered[0].extra["badge"], "new");
+        assert!(!recovered[0].extra.contains_key("stars"), "{}", theme.id);
+    }
+
+    // without a repo, a language or license field is the user's own
+    let mut config = PortfolioConfig::default();
+    let project = &mut config.projects[0];
+    project.repo = None;
+    project
+        .extra
+        .insert("language".to_string(), serde_json::json!("klingon"));
+    project.extra.insert(
+        "license".to_string(),
+        serde_json::json!("all rights reserved"),
+    );
+    for theme in Theme::available_themes() {
+        let adapted = theme.adapt_projects(&config.projects).unwrap();
+        assert_eq!(adapted[0]["language"], "klingon", "{}", theme.id);
+        assert!(adapted[0]["stars"].is_null(), "{}", theme.id);
+
+        let recovered = theme.recover_projects(&adapted).unwrap();
+        assert_eq!(recovered[0].repo, None, "{}", theme.id);
+        assert_eq!(recovered[0].extra, config.projects[0].extra, "{}", theme.id);
+    }
+
+    // configs written before typed metadata kept it in extra
+    let mut legacy = serde_json::to_value(PortfolioConfig::default()).unwrap();
+    legacy["projects"][0]["stars"] = serde_json::json!(7);
+    legacy["projects"][0]["forks"] = serde_json::json!(2);
+    legacy["projects"][0]["topics"] = serde_json::json!("games, rust");
+    legacy["projects"][0]["language"] = serde_json::json!("Rust");
+    legacy["projects"][0]["license"] = serde_json::json!("MIT");
+    legacy["projects"][0]["archived"] = serde_json::json!(true);
+    let legacy: PortfolioConfig = serde_json::from_value(legacy).unwrap();
+    let project = &legacy.projects[0];
+    assert_eq!(project.stars(), 7);
+    let repo = project.repo.as_ref().unwrap();
+    assert_eq!(repo.forks, 2);
+    assert_eq!(repo.topics, vec!["games", "rust"]);
+    assert_eq!(repo.language.as_deref(), Some("Rust"));
+    assert_eq!(repo.license.as_deref(), Some("MIT"));
+    assert!(repo.archived);
+    assert!(project.extra.is_empty(), "{:?}", project.extra);
+
+    // and the typed form round-trips through the config file
+    let saved = serde_json::to_string(&legacy).unwrap();
+    let reloaded: PortfolioConfig = serde_json::from_str(&saved).unwrap();
+    assert_eq!(reloaded.projects[0].repo, legacy.projects[0].repo);
+}